@@ -0,0 +1,546 @@
+//! Download puzzle inputs and submit answers to the Advent of Code website
+//!
+//! Requests go through a [`Transport`], which is [`HttpsTransport`] for the real website when the
+//! `ureq` feature is enabled. [`TcpTransport`] speaks plain HTTP over a socket and is meant for
+//! servers running locally, such as `testing::mock_server`, which the tests and the `mock` feature
+//! build.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::Part;
+
+/// Address of the Advent of Code website
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Year of the calendar solved by this workspace
+pub const YEAR: u32 = 2023;
+
+/// Identifies this tool to the website, as requested by its maintainers
+pub const USER_AGENT: &str = "github.com/qthibeault/advent-of-code-2023";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Method::Get => write!(f, "GET"),
+            Method::Post => write!(f, "POST"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Way of sending HTTP requests, so the client can be pointed at something else than the website
+pub trait Transport {
+    fn send(&self, request: &Request) -> io::Result<Response>;
+}
+
+/// Plain HTTP/1.1 over a TCP socket, without TLS or chunked responses
+///
+/// This is only suitable for servers running locally, the website requires [`HttpsTransport`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcpTransport;
+
+impl Transport for TcpTransport {
+    fn send(&self, request: &Request) -> io::Result<Response> {
+        let rest = request.url.strip_prefix("http://").ok_or_else(|| {
+            let msg = format!("Only http:// URLs can be sent over TCP: {}", request.url);
+            io::Error::new(io::ErrorKind::Unsupported, msg)
+        })?;
+
+        let (host, path) = match rest.find('/') {
+            Some(n) => rest.split_at(n),
+            None => (rest, "/"),
+        };
+
+        let mut stream = TcpStream::connect(host)?;
+        let body = request.body.as_deref().unwrap_or("");
+        let mut head = format!("{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n", request.method, path, host);
+
+        for (name, value) in &request.headers {
+            head.push_str(&format!("{}: {}\r\n", name, value));
+        }
+
+        if request.body.is_some() {
+            head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        }
+
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(body.as_bytes())?;
+
+        read_response(BufReader::new(stream))
+    }
+}
+
+fn invalid_data(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
+
+fn read_response<R: BufRead>(mut reader: R) -> io::Result<Response> {
+    let mut status_line = String::new();
+    reader.read_line(&mut status_line)?;
+
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| invalid_data(format!("Invalid status line: {:?}", status_line.trim_end())))?;
+
+    let mut content_length = None;
+
+    loop {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+
+    let mut body = Vec::new();
+
+    match content_length {
+        Some(n) => {
+            body.resize(n, 0);
+            reader.read_exact(&mut body)?;
+        }
+        None => {
+            reader.read_to_end(&mut body)?;
+        }
+    }
+
+    let body = String::from_utf8(body).map_err(|_| invalid_data("Response body is not UTF-8"))?;
+
+    Ok(Response { status, body })
+}
+
+/// HTTPS requests to the website
+#[cfg(feature = "ureq")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HttpsTransport;
+
+#[cfg(feature = "ureq")]
+impl Transport for HttpsTransport {
+    fn send(&self, request: &Request) -> io::Result<Response> {
+        let mut req = ureq::request(&request.method.to_string(), &request.url);
+
+        for (name, value) in &request.headers {
+            req = req.set(name, value);
+        }
+
+        let result = match &request.body {
+            Some(body) => req.send_string(body),
+            None => req.call(),
+        };
+
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(io::Error::other(err)),
+        };
+
+        let status = response.status();
+        let body = response.into_string()?;
+
+        Ok(Response { status, body })
+    }
+}
+
+/// Outcome of submitting an answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint of which way
+    Incorrect,
+    /// An answer was submitted too recently, `wait` is how long until the next one is accepted
+    RateLimited { wait: Option<Duration> },
+    /// The part has already been solved, or is not unlocked yet
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Interpret the page returned after submitting an answer
+    pub fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Verdict::Incorrect)
+        } else if page.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited { wait: parse_wait(page) })
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "incorrect, too high"),
+            Verdict::TooLow => write!(f, "incorrect, too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, wait {}s before submitting again", wait.as_secs())
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "part already solved or not unlocked yet"),
+        }
+    }
+}
+
+/// Read the `You have 1m 5s left to wait` part of a rate limited response
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    let mut seconds = 0;
+
+    for amount in page[start..end].split_whitespace() {
+        let (value, multiplier) = if let Some(m) = amount.strip_suffix('m') {
+            (m, 60)
+        } else if let Some(s) = amount.strip_suffix('s') {
+            (s, 1)
+        } else {
+            return None;
+        };
+
+        seconds += value.parse::<u64>().ok()? * multiplier;
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    Io(io::Error),
+    /// The website answered with an unexpected status code
+    Status { status: u16, body: String },
+    /// The page returned after submitting an answer could not be understood
+    UnknownResponse(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Io(err) => write!(f, "{}", err),
+            ClientError::Status { status: 400, .. } => write!(f, "Request rejected, is the session cookie valid?"),
+            ClientError::Status { status: 404, .. } => write!(f, "Puzzle not found, is it unlocked yet?"),
+            ClientError::Status { status, body } => {
+                write!(f, "Unexpected status {}: {}", status, body.lines().next().unwrap_or(""))
+            }
+            ClientError::UnknownResponse(_) => write!(f, "Could not understand the response to the answer"),
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+/// Client for the Advent of Code website, authenticated by a session cookie
+#[derive(Debug, Clone)]
+pub struct Client<T> {
+    transport: T,
+    session: String,
+    base_url: String,
+    root: PathBuf,
+}
+
+impl<T: Transport> Client<T> {
+    /// Client for the website that caches inputs in `inputs/` under the current directory
+    pub fn new(transport: T, session: impl Into<String>) -> Self {
+        Self {
+            transport,
+            session: session.into(),
+            base_url: BASE_URL.to_string(),
+            root: PathBuf::from("."),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Directory under which downloaded inputs are stored, as `inputs/dayN.txt`
+    pub fn with_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = root.into();
+        self
+    }
+
+    pub fn cache_path(&self, day: u32) -> PathBuf {
+        self.root.join(crate::input::file_name(day))
+    }
+
+    fn request(&self, method: Method, path: &str, body: Option<String>) -> Result<Response, ClientError> {
+        let mut headers = vec![
+            ("Cookie".to_string(), format!("session={}", self.session)),
+            ("User-Agent".to_string(), USER_AGENT.to_string()),
+        ];
+
+        if body.is_some() {
+            headers.push(("Content-Type".to_string(), "application/x-www-form-urlencoded".to_string()));
+        }
+
+        let request = Request {
+            method,
+            url: format!("{}/{}/day/{}", self.base_url, YEAR, path),
+            headers,
+            body,
+        };
+
+        let response = self.transport.send(&request)?;
+
+        if response.status == 200 {
+            Ok(response)
+        } else {
+            Err(ClientError::Status { status: response.status, body: response.body })
+        }
+    }
+
+    /// Puzzle input of a day, downloaded only if it is not in the cache yet
+    ///
+    /// A blank cache file, such as the placeholder of a new day or what is left of an interrupted
+    /// write, does not count as cached and is overwritten.
+    pub fn input(&self, day: u32) -> Result<String, ClientError> {
+        let path = self.cache_path(day);
+
+        if let Ok(input) = fs::read_to_string(&path) {
+            if !input.trim().is_empty() {
+                return Ok(input);
+            }
+        }
+
+        let response = self.request(Method::Get, &format!("{}/input", day), None)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(&path, &response.body)?;
+
+        Ok(response.body)
+    }
+
+    pub fn submit(&self, day: u32, part: Part, answer: &str) -> Result<Verdict, ClientError> {
+        let body = format!("level={}&answer={}", part.number(), answer);
+        let response = self.request(Method::Post, &format!("{}/answer", day), Some(body))?;
+
+        Verdict::parse(&response.body).ok_or(ClientError::UnknownResponse(response.body))
+    }
+}
+
+/// Local server standing in for the website, to check clients offline
+#[cfg(any(test, feature = "mock"))]
+pub mod testing {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// Server on a local port answering each connection with the next of the given responses
+    ///
+    /// Every request received is sent back through the channel, as its head followed by its body.
+    pub fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind mock server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().expect("Could not accept connection");
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if let Some(n) = line.strip_prefix("Content-Length: ") {
+                        content_length = n.trim().parse().unwrap();
+                    }
+
+                    request.push_str(&line);
+
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut body_bytes = vec![0; content_length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                request.push_str(&String::from_utf8(body_bytes).unwrap());
+                sender.send(request).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body,
+                );
+
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::Duration;
+
+    use crate::Part;
+
+    use super::testing::mock_server;
+    use super::{Client, ClientError, TcpTransport, Verdict};
+
+    fn scratch_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn download_and_cache() {
+        let (url, requests) = mock_server(vec![(200, "1abc2\npqr3stu8vwx\n")]);
+        let cache = scratch_dir("cache");
+        let client = Client::new(TcpTransport, "secret")
+            .with_base_url(url)
+            .with_root(&cache);
+
+        assert_eq!(client.input(1).unwrap(), "1abc2\npqr3stu8vwx\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=secret\r\n"));
+
+        // The mock server only answers once, so this must come from the cache
+        assert_eq!(client.input(1).unwrap(), "1abc2\npqr3stu8vwx\n");
+        assert_eq!(fs::read_to_string(cache.join("inputs").join("day1.txt")).unwrap(), "1abc2\npqr3stu8vwx\n");
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn blank_cache() {
+        let (url, requests) = mock_server(vec![(200, "Game 1: 3 blue\n")]);
+        let cache = scratch_dir("blank");
+        let client = Client::new(TcpTransport, "secret")
+            .with_base_url(url)
+            .with_root(&cache);
+
+        fs::create_dir_all(cache.join("inputs")).unwrap();
+        fs::write(cache.join("inputs").join("day2.txt"), " \n").unwrap();
+
+        assert_eq!(client.input(2).unwrap(), "Game 1: 3 blue\n");
+        assert!(requests.recv().unwrap().starts_with("GET /2023/day/2/input HTTP/1.1\r\n"));
+        assert_eq!(fs::read_to_string(cache.join("inputs").join("day2.txt")).unwrap(), "Game 1: 3 blue\n");
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn unavailable_input() {
+        let (url, _requests) = mock_server(vec![(404, "Not found")]);
+        let cache = scratch_dir("missing");
+        let client = Client::new(TcpTransport, "secret")
+            .with_base_url(url)
+            .with_root(&cache);
+
+        assert!(matches!(client.input(25), Err(ClientError::Status { status: 404, .. })));
+        assert!(!cache.join("inputs").join("day25.txt").exists());
+    }
+
+    #[test]
+    fn submit() {
+        let (url, requests) = mock_server(vec![
+            (200, "<article><p>That's the right answer! You are one gold star closer.</p></article>"),
+            (200, "<p>That's not the right answer; your answer is too high.</p>"),
+            (200, "<p>Welcome to the Advent of Code</p>"),
+        ]);
+
+        let client = Client::new(TcpTransport, "secret").with_base_url(url);
+
+        assert_eq!(client.submit(7, Part::Two, "5905").unwrap(), Verdict::Correct);
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/7/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=5905"));
+
+        assert_eq!(client.submit(7, Part::One, "9999").unwrap(), Verdict::TooHigh);
+        assert!(matches!(client.submit(7, Part::One, "1"), Err(ClientError::UnknownResponse(_))));
+    }
+
+    #[test]
+    fn verdicts() {
+        let pages = [
+            ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+            ("That's not the right answer.  If you're stuck, make sure...", Verdict::Incorrect),
+            ("You don't seem to be solving the right level.  Did you already complete it?", Verdict::WrongLevel),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before \
+                 trying again.  You have 1m 5s left to wait.",
+                Verdict::RateLimited { wait: Some(Duration::from_secs(65)) },
+            ),
+            ("You gave an answer too recently.", Verdict::RateLimited { wait: None }),
+        ];
+
+        for (page, verdict) in pages {
+            assert_eq!(Verdict::parse(page), Some(verdict), "{}", page);
+        }
+
+        assert_eq!(Verdict::parse("<html></html>"), None);
+    }
+}
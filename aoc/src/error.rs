@@ -0,0 +1,147 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// Error raised when the puzzle input does not have the expected shape
+///
+/// Lines and columns start at 1, and columns are counted in characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new<T, M>(line: usize, column: usize, text: T, message: M) -> Self
+    where
+        T: Into<String>,
+        M: Into<String>,
+    {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Error pointing at `fragment`, which must be a slice of `source`
+    pub fn at<M: Into<String>>(source: &str, fragment: &str, message: M) -> Self {
+        let (line, column) = location(source, offset(source, fragment));
+        let text = fragment.lines().next().unwrap_or("");
+
+        Self::new(line, column, text, message)
+    }
+
+    /// Error pointing at the token that starts `rest`, which must be a slice of `source`
+    ///
+    /// This is convenient for parsers that only know the input they have not consumed yet.
+    pub fn near<M: Into<String>>(source: &str, rest: &str, message: M) -> Self {
+        Self::at(source, token(rest), message)
+    }
+
+    /// Move an error raised while parsing `slice` to its position within `source`
+    ///
+    /// Parsers for a single line or block report positions relative to that slice, the caller
+    /// holding the full input uses this to turn them into positions in the input.
+    pub fn within(self, source: &str, slice: &str) -> Self {
+        let (line, column) = location(source, offset(source, slice));
+
+        Self {
+            line: line + self.line - 1,
+            column: if self.line == 1 { column + self.column - 1 } else { self.column },
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+
+        if !self.text.is_empty() {
+            write!(f, " at {:?}", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Leading token of the input: a run of non-whitespace characters, or the first character
+pub fn token(input: &str) -> &str {
+    let end = match input.find(char::is_whitespace) {
+        Some(0) => input.chars().next().map_or(0, char::len_utf8),
+        Some(n) => n,
+        None => input.len(),
+    };
+
+    &input[..end]
+}
+
+fn offset(source: &str, fragment: &str) -> usize {
+    let start = source.as_ptr() as usize;
+    let position = fragment.as_ptr() as usize;
+
+    debug_assert!(
+        start <= position && position <= start + source.len(),
+        "Fragment is not part of the source",
+    );
+
+    position.saturating_sub(start).min(source.len())
+}
+
+fn location(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |n| n + 1);
+    let column = before[line_start..].chars().count() + 1;
+
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, token};
+
+    static INPUT: &str = "32T3K 765\nT55J5 684\nKK6X7 28\n";
+
+    #[test]
+    fn at() {
+        let line = INPUT.lines().nth(2).unwrap();
+        let err = ParseError::at(INPUT, &line[3..4], "unknown card");
+
+        assert_eq!(err, ParseError::new(3, 4, "X", "unknown card"));
+        assert_eq!(err.to_string(), "line 3, column 4: unknown card at \"X\"");
+    }
+
+    #[test]
+    fn near() {
+        let rest = &INPUT[16..];
+        let err = ParseError::near(INPUT, rest, "expected a number");
+
+        assert_eq!(err, ParseError::new(2, 7, "684", "expected a number"));
+    }
+
+    #[test]
+    fn within() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let err = ParseError::at(line, &line[6..], "bad bet").within(INPUT, line);
+
+        assert_eq!(err, ParseError::new(2, 7, "684", "bad bet"));
+
+        let block = &INPUT[10..];
+        let err = ParseError::new(2, 4, "X", "unknown card").within(INPUT, block);
+
+        assert_eq!(err, ParseError::new(3, 4, "X", "unknown card"));
+    }
+
+    #[test]
+    fn tokens() {
+        assert_eq!(token("684\nKK6X7"), "684");
+        assert_eq!(token(" 684"), " ");
+        assert_eq!(token(""), "");
+    }
+}
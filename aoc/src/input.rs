@@ -0,0 +1,137 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+/// Directory holding the puzzle inputs, one `dayN.txt` file per day
+pub const INPUT_DIR: &str = "inputs";
+
+/// Where the puzzle input for a day is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Determine the input source for a day
+    ///
+    /// An explicit path always wins, with `-` standing for stdin. Without one, `inputs/dayN.txt`
+    /// is looked up in the current directory and its parents, and stdin is used as a last resort
+    /// when it is not attached to a terminal.
+    pub fn resolve(day: u32, path: Option<&Path>) -> io::Result<Self> {
+        match path {
+            Some(p) if p == Path::new("-") => Ok(Source::Stdin),
+            Some(p) => Ok(Source::File(p.to_path_buf())),
+            None => {
+                let cwd = env::current_dir()?;
+
+                if let Some(p) = find(day, &cwd) {
+                    Ok(Source::File(p))
+                } else if !io::stdin().is_terminal() {
+                    Ok(Source::Stdin)
+                } else {
+                    let msg = format!(
+                        "No input for day {}: pass a path, create {} or pipe it on stdin",
+                        day,
+                        file_name(day).display(),
+                    );
+
+                    Err(io::Error::new(io::ErrorKind::NotFound, msg))
+                }
+            }
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::File(p) => fs::read_to_string(p).map_err(|err| {
+                io::Error::new(err.kind(), format!("Could not read {}: {}", p.display(), err))
+            }),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Open the input to read it a line at a time instead of all at once
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::File(p) => {
+                let file = fs::File::open(p).map_err(|err| {
+                    io::Error::new(err.kind(), format!("Could not read {}: {}", p.display(), err))
+                })?;
+
+                Ok(Box::new(BufReader::new(file)))
+            }
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+/// Path of the input file for a day, relative to the workspace root
+pub fn file_name(day: u32) -> PathBuf {
+    Path::new(INPUT_DIR).join(format!("day{}.txt", day))
+}
+
+/// Search `start` and its ancestors for the input file of a day
+pub fn find(day: u32, start: &Path) -> Option<PathBuf> {
+    let name = file_name(day);
+
+    start
+        .ancestors()
+        .map(|dir| dir.join(&name))
+        .find(|p| p.is_file())
+}
+
+/// Read the puzzle input for a day, see [`Source::resolve`] for where it is looked up
+pub fn read(day: u32, path: Option<&Path>) -> io::Result<String> {
+    Source::resolve(day, path)?.read()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::BufRead;
+    use std::path::{Path, PathBuf};
+
+    use super::{Source, file_name, find};
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("Could not create scratch directory");
+        dir
+    }
+
+    #[test]
+    fn explicit_path() {
+        let path = Path::new("puzzle.txt");
+
+        assert_eq!(Source::resolve(3, Some(path)).unwrap(), Source::File(path.to_path_buf()));
+        assert_eq!(Source::resolve(3, Some(Path::new("-"))).unwrap(), Source::Stdin);
+    }
+
+    #[test]
+    fn find_in_ancestors() {
+        let root = scratch_dir("find");
+        let nested = root.join("day4").join("src");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir_all(root.join("inputs")).unwrap();
+        fs::write(root.join(file_name(4)), "Card 1: 1 | 1\n").unwrap();
+
+        assert_eq!(find(4, &nested), Some(root.join("inputs").join("day4.txt")));
+        assert_eq!(find(5, &nested), None);
+
+        let source = Source::File(find(4, &root).unwrap());
+        assert_eq!(source.read().unwrap(), "Card 1: 1 | 1\n");
+
+        let mut line = String::new();
+        source.reader().unwrap().read_line(&mut line).unwrap();
+        assert_eq!(line, "Card 1: 1 | 1\n");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,252 @@
+mod error;
+pub mod client;
+pub mod input;
+pub mod parallel;
+pub mod parse;
+pub mod stream;
+
+use std::any::type_name;
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+pub use error::{ParseError, token};
+
+/// Interface implemented by the solution for each day of the advent calendar
+///
+/// The input is parsed once and then shared between both parts. Parsed inputs are allowed to
+/// borrow from the puzzle text, which is why the input type carries a lifetime.
+pub trait Solution {
+    const DAY: u32;
+
+    type Input<'a>;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Self::Part1;
+    fn part2(input: &Self::Input<'_>) -> Self::Part2;
+}
+
+/// Random puzzle inputs for a day, used to stress the parser and cross-check solutions
+///
+/// Inputs follow the structure of the real ones, and the same seed and size always give the same
+/// input. What the size measures depends on the day, such as the number of lines or the width of a
+/// grid, and the cost of solving an input grows with it.
+pub trait Generate {
+    fn generate(seed: u64, size: usize) -> String;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u32> for Part {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            n => Err(n),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Part {}", self.number())
+    }
+}
+
+/// Answer for a part that has not been solved yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    /// Name of the type the part returned its answer as
+    pub type_name: &'static str,
+    /// Time spent solving the part, not including parsing the input
+    pub time: Duration,
+}
+
+/// Answers for the parts of a day that were solved, along with the time spent parsing the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl Solved {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.answers.iter().find(|answer| answer.part == part)
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.answers.iter().map(|answer| answer.time).sum::<Duration>()
+    }
+}
+
+/// Parse the input and solve the requested parts, or both parts if none is given
+///
+/// Parsing and each part are timed separately. Formatting an answer is included in the time of
+/// its part.
+pub fn solve<S: Solution>(input: &str, part: Option<Part>) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = match part {
+        Some(p) => vec![p],
+        None => Part::ALL.to_vec(),
+    };
+
+    let answers = parts
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let (value, type_name) = match part {
+                Part::One => (S::part1(&parsed).to_string(), type_name::<S::Part1>()),
+                Part::Two => (S::part2(&parsed).to_string(), type_name::<S::Part2>()),
+            };
+
+            Answer { part, value, type_name, time: start.elapsed() }
+        })
+        .collect();
+
+    Ok(Solved { parse_time, answers })
+}
+
+/// A type-erased solution that can be stored alongside the other days
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    pub solve: fn(&str, Option<Part>) -> Result<Solved, ParseError>,
+    /// Random input of the given seed and size, see [`Generate`]
+    pub generate: fn(u64, usize) -> String,
+    /// Solve an input read one line at a time, for the days that can, see [`stream::Stream`]
+    pub stream: Option<stream::SolveFn>,
+}
+
+impl Day {
+    pub const fn new<S: Solution + Generate>() -> Self {
+        Self {
+            number: S::DAY,
+            solve: solve::<S>,
+            generate: S::generate,
+            stream: None,
+        }
+    }
+
+    /// Day whose input can also be solved one line at a time
+    pub const fn streaming<S: Solution + Generate + stream::Stream>() -> Self {
+        Self {
+            stream: Some(stream::solve::<S>),
+            ..Self::new::<S>()
+        }
+    }
+}
+
+/// Entry point shared by the binaries of each day
+///
+/// The only argument is an optional path to the puzzle input, see [`input::Source::resolve`].
+pub fn main<S: Solution>() -> ExitCode {
+    let path = std::env::args_os().nth(1).map(PathBuf::from);
+    let input = match input::read(S::DAY, path.as_deref()) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match solve::<S>(&input, None) {
+        Ok(solved) => {
+            for answer in solved.answers {
+                println!("{}: {}", answer.part, answer.value);
+            }
+
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Could not parse input: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, Part, Solution, solve};
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        const DAY: u32 = 0;
+
+        type Input<'a> = Vec<&'a str>;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            if input.is_empty() {
+                return Err(ParseError::new(1, 1, "", "empty input"));
+            }
+
+            Ok(input.lines().collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> usize {
+            input.len()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> usize {
+            input.iter().map(|line| line.len()).sum()
+        }
+    }
+
+    #[test]
+    fn solve_parts() {
+        let solved = solve::<Lengths>("ab\ncde", None).unwrap();
+        let answers = &solved.answers;
+
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[0].part, Part::One);
+        assert_eq!(answers[0].value, "2");
+        assert_eq!(answers[1].part, Part::Two);
+        assert_eq!(answers[1].value, "5");
+        assert_eq!(answers[1].type_name, "usize");
+
+        let solved = solve::<Lengths>("ab\ncde", Some(Part::Two)).unwrap();
+
+        assert_eq!(solved.answers.len(), 1);
+        assert_eq!(solved.answer(Part::Two).map(|a| a.value.as_str()), Some("5"));
+        assert_eq!(solved.answer(Part::One), None);
+        assert!(solved.total_time() >= solved.parse_time);
+
+        assert_eq!(solve::<Lengths>("", None).err(), Some(ParseError::new(1, 1, "", "empty input")));
+    }
+}
@@ -0,0 +1,58 @@
+//! Work over independent records, spread over a thread pool with the `parallel` feature
+//!
+//! Without the feature every function runs on the calling thread. Results do not depend on how
+//! the work was split: [`map`] keeps the order of the records, and [`sum`] is only used with
+//! integers, whose sum does not depend on the order of the terms.
+
+use std::iter::Sum;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Sum of `f` over every record
+pub fn sum<T, S, F>(records: &[T], f: F) -> S
+where
+    T: Sync,
+    S: Sum + Send,
+    F: Fn(&T) -> S + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return records.par_iter().map(f).sum();
+
+    #[cfg(not(feature = "parallel"))]
+    return records.iter().map(f).sum();
+}
+
+/// Result of `f` for every record, in the order of the records
+pub fn map<T, U, F>(records: &[T], f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    return records.par_iter().map(f).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    return records.iter().map(f).collect();
+}
+
+/// Set the number of threads of the pool, which can only be done before it is first used
+#[cfg(feature = "parallel")]
+pub fn set_threads(n: usize) -> Result<(), rayon::ThreadPoolBuildError> {
+    rayon::ThreadPoolBuilder::new().num_threads(n).build_global()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map, sum};
+
+    #[test]
+    fn records() {
+        let records: Vec<u64> = (0..10_000).collect();
+
+        assert_eq!(sum(&records, |&n| n * n), 333_283_335_000);
+        assert_eq!(map(&records, |&n| n % 7), records.iter().map(|n| n % 7).collect::<Vec<_>>());
+        assert_eq!(sum(&[] as &[u64], |&n| n), 0);
+    }
+}
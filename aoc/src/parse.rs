@@ -0,0 +1,197 @@
+//! Building blocks for the parsers of every day
+//!
+//! Functions take the full `source` of the input along with the slice of it they parse, so that
+//! errors point at their position in the input as with [`ParseError::at`]. The slices they return
+//! are taken from the slice they were given and can be passed on to the other functions.
+
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Non-blank lines of the input, without surrounding whitespace
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim).filter(|line| !line.is_empty())
+}
+
+/// Groups of lines separated by one or more blank lines, without surrounding whitespace
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = input.lines().peekable();
+
+    std::iter::from_fn(move || {
+        let first = lines.find(|line| !line.trim().is_empty())?;
+        let mut last = first;
+
+        while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+            last = line;
+        }
+
+        let start = first.as_ptr() as usize - input.as_ptr() as usize;
+        let end = last.as_ptr() as usize - input.as_ptr() as usize + last.len();
+
+        Some(input[start..end].trim())
+    })
+}
+
+/// Parse a token as a number
+pub fn number<T: FromStr>(source: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(source, token, "expected a number"))
+}
+
+/// Parse numbers separated by whitespace, which may be none
+pub fn numbers<T: FromStr>(source: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    text
+        .split_whitespace()
+        .map(|token| number(source, token))
+        .collect()
+}
+
+/// Parse numbers separated by `separator`, along with any whitespace around it
+pub fn separated<T: FromStr>(source: &str, text: &str, separator: char) -> Result<Vec<T>, ParseError> {
+    text
+        .split(separator)
+        .map(|token| number(source, token.trim()))
+        .collect()
+}
+
+/// Text following a `Label:` prefix at the start of `line`
+pub fn label<'a>(source: &str, line: &'a str, label: &str) -> Result<&'a str, ParseError> {
+    let line = line.trim_start();
+
+    line.strip_prefix(label)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(|| ParseError::near(source, line, format!("expected \"{}:\"", label)))
+}
+
+/// Number of a `Label N:` prefix at the start of `line`, along with the text following it
+///
+/// Any amount of whitespace may separate the label from the number, as in `Card   1:`.
+pub fn numbered<'a, T: FromStr>(source: &str, line: &'a str, label: &str) -> Result<(T, &'a str), ParseError> {
+    let line = line.trim_start();
+    let expected = || ParseError::near(source, line, format!("expected \"{} N:\"", label));
+
+    let (n, rest) = line
+        .strip_prefix(label)
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .and_then(|rest| rest.trim_start().split_once(':'))
+        .ok_or_else(expected)?;
+
+    Ok((number(source, n)?, rest))
+}
+
+/// Convert every character of `text` with `cell`, reporting its error at that character
+pub fn chars<T, F, E>(source: &str, text: &str, mut cell: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(char) -> Result<T, E>,
+    E: Into<String>,
+{
+    text
+        .char_indices()
+        .map(|(i, c)| cell(c).map_err(|err| ParseError::at(source, &text[i..i + c.len_utf8()], err)))
+        .collect()
+}
+
+/// Cells of a grid with one row per line of `text`, given row by row along with the width
+///
+/// Lines are trimmed and blank lines are skipped. Every row must have the same number of cells,
+/// and the error returned by `cell` is reported at the character it was given.
+pub fn grid<T, F, E>(source: &str, text: &str, mut cell: F) -> Result<(Vec<T>, usize), ParseError>
+where
+    F: FnMut(char) -> Result<T, E>,
+    E: Into<String>,
+{
+    let mut cells = Vec::new();
+    let mut width = None;
+
+    for line in lines(text) {
+        let start = cells.len();
+
+        for (i, c) in line.char_indices() {
+            let value = cell(c)
+                .map_err(|err| ParseError::at(source, &line[i..i + c.len_utf8()], err))?;
+
+            cells.push(value);
+        }
+
+        let row_width = cells.len() - start;
+
+        match width {
+            None => width = Some(row_width),
+            Some(w) if w != row_width => {
+                let message = format!("expected a row of {} cells, found {}", w, row_width);
+                return Err(ParseError::at(source, line, message));
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok((cells, width.unwrap_or(0)))
+}
+
+/// Check that only whitespace is left in `rest` once `what` has been parsed
+pub fn end(source: &str, rest: &str, what: &str) -> Result<(), ParseError> {
+    match rest.trim_start() {
+        "" => Ok(()),
+        rest => Err(ParseError::near(source, rest, format!("unexpected text after {}", what))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError;
+
+    use super::{blocks, chars, end, grid, label, lines, number, numbered, numbers, separated};
+
+    static INPUT: &str = "seeds: 79 14\n\nseed-to-soil map:\n 50 98 2\n  \n\n\nsoil-to-fertilizer map:\n0 15 x\n";
+
+    #[test]
+    fn splitting() {
+        assert_eq!(lines(INPUT).collect::<Vec<_>>(), ["seeds: 79 14", "seed-to-soil map:", "50 98 2", "soil-to-fertilizer map:", "0 15 x"]);
+        assert_eq!(
+            blocks(INPUT).collect::<Vec<_>>(),
+            ["seeds: 79 14", "seed-to-soil map:\n 50 98 2", "soil-to-fertilizer map:\n0 15 x"],
+        );
+        assert_eq!(blocks("\n \n").count(), 0);
+    }
+
+    #[test]
+    fn numbers_and_labels() {
+        let seeds = label(INPUT, lines(INPUT).next().unwrap(), "seeds").unwrap();
+
+        assert_eq!(numbers::<u64>(INPUT, seeds), Ok(vec![79, 14]));
+        assert_eq!(numbers::<u64>(INPUT, ""), Ok(vec![]));
+        assert_eq!(label(INPUT, &INPUT[14..], "seeds"), Err(ParseError::new(3, 1, "seed-to-soil", "expected \"seeds:\"")));
+
+        let last = lines(INPUT).last().unwrap();
+
+        assert_eq!(numbers::<u64>(INPUT, last), Err(ParseError::new(9, 6, "x", "expected a number")));
+        assert_eq!(number::<u32>("-1", "-1"), Err(ParseError::new(1, 1, "-1", "expected a number")));
+        assert_eq!(separated::<usize>("1, 1,3", "1, 1,3", ','), Ok(vec![1, 1, 3]));
+        assert_eq!(separated::<usize>("1,,3", "1,,3", ','), Err(ParseError::new(1, 3, "", "expected a number")));
+
+        let line = "Card   12: 41 48 | 83";
+
+        assert_eq!(numbered(line, line, "Card"), Ok((12, " 41 48 | 83")));
+        assert_eq!(numbered::<u32>(line, line, "Game"), Err(ParseError::new(1, 1, "Card", "expected \"Game N:\"")));
+        assert_eq!(numbered::<u32>("Card1:", "Card1:", "Card"), Err(ParseError::new(1, 1, "Card1:", "expected \"Card N:\"")));
+        assert_eq!(numbered::<u32>("Card x:", "Card x:", "Card"), Err(ParseError::new(1, 6, "x", "expected a number")));
+    }
+
+    #[test]
+    fn characters() {
+        let digit = |c: char| c.to_digit(10).ok_or("expected a digit");
+
+        assert_eq!(chars("123", "123", digit), Ok(vec![1, 2, 3]));
+        assert_eq!(chars("1é3x", "1é3x", digit), Err(ParseError::new(1, 2, "é", "expected a digit")));
+        assert_eq!(grid("12\n34\n", "12\n34\n", digit), Ok((vec![1, 2, 3, 4], 2)));
+        assert_eq!(grid("12\n3\n", "12\n3\n", digit), Err(ParseError::new(2, 1, "3", "expected a row of 2 cells, found 1")));
+        assert_eq!(grid("", "", digit), Ok((vec![], 0)));
+    }
+
+    #[test]
+    fn ends() {
+        assert_eq!(end("1 2  ", "  ", "numbers"), Ok(()));
+        assert_eq!(end("1 2 x", &"1 2 x"[3..], "numbers"), Err(ParseError::new(1, 5, "x", "unexpected text after numbers")));
+    }
+}
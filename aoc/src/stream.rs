@@ -0,0 +1,210 @@
+//! Solving inputs read one line at a time, for inputs too large to be held in memory
+//!
+//! Days whose input is a list of independent lines implement [`Stream`], which reads and parses
+//! the lines from any [`BufRead`] as the parts are solved. Only the current line is kept, along
+//! with whatever the parts need to remember about the lines before it.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead};
+use std::time::{Duration, Instant};
+
+use crate::{Answer, ParseError, Part, Solution, Solved};
+
+/// Error raised while solving a streamed input, when reading or parsing a line fails
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "Could not read input: {}", err),
+            ReadError::Parse(err) => write!(f, "Could not parse input: {}", err),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(err) => Some(err),
+            ReadError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        ReadError::Parse(err)
+    }
+}
+
+/// Iterator over the parsed lines of a reader, see [`lines`]
+pub struct Lines<R, F> {
+    reader: R,
+    parse: F,
+    buffer: String,
+    line: usize,
+}
+
+impl<R, T, F> Iterator for Lines<R, F>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    type Item = Result<T, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buffer.clear();
+
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(_) => self.line += 1,
+                Err(err) => return Some(Err(err.into())),
+            }
+
+            let line = self.buffer.trim_end_matches(['\n', '\r']);
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let line_number = self.line;
+            let record = (self.parse)(line).map_err(|err| ParseError {
+                line: line_number + err.line - 1,
+                ..err
+            });
+
+            return Some(record.map_err(ReadError::from));
+        }
+    }
+}
+
+/// Parse every non-blank line read from `reader` with `parse`
+///
+/// Each line is given to `parse` without its line ending, as its own source, and the errors it
+/// returns are moved to the position of the line in the whole input. Lines are read into the same
+/// buffer, so memory does not grow with the length of the input.
+pub fn lines<R, T, F>(reader: R, parse: F) -> Lines<R, F>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    Lines { reader, parse, buffer: String::new(), line: 0 }
+}
+
+/// Solution that can solve both parts while reading its input one line at a time
+pub trait Stream: Solution {
+    fn stream<R: BufRead>(reader: R) -> Result<(Self::Part1, Self::Part2), ReadError>;
+}
+
+/// Type-erased [`solve`], as stored in a [`Day`](crate::Day)
+pub type SolveFn = fn(&mut dyn BufRead, Option<Part>) -> Result<Solved, ReadError>;
+
+/// Solve the requested parts of a streamed input, or both parts if none is given
+///
+/// Reading, parsing and solving are interleaved, so the time they take is counted as parsing time
+/// and the answers take no time of their own.
+pub fn solve<S: Stream>(reader: &mut dyn BufRead, part: Option<Part>) -> Result<Solved, ReadError> {
+    let start = Instant::now();
+    let (part1, part2) = S::stream(reader)?;
+    let parse_time = start.elapsed();
+
+    let answers = [
+        (Part::One, part1.to_string(), std::any::type_name::<S::Part1>()),
+        (Part::Two, part2.to_string(), std::any::type_name::<S::Part2>()),
+    ];
+
+    let answers = answers
+        .into_iter()
+        .filter(|(p, _, _)| part.is_none_or(|part| part == *p))
+        .map(|(part, value, type_name)| Answer { part, value, type_name, time: Duration::ZERO })
+        .collect();
+
+    Ok(Solved { parse_time, answers })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Cursor};
+
+    use crate::{ParseError, Part, Solution, parse};
+
+    use super::{ReadError, Stream, lines, solve};
+
+    struct Sums;
+
+    impl Solution for Sums {
+        const DAY: u32 = 0;
+
+        type Input<'a> = Vec<Vec<u32>>;
+        type Part1 = u32;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            parse::lines(input).map(|line| parse::numbers(input, line)).collect()
+        }
+
+        fn part1(input: &Self::Input<'_>) -> u32 {
+            input.iter().flatten().sum()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> usize {
+            input.len()
+        }
+    }
+
+    impl Stream for Sums {
+        fn stream<R: BufRead>(reader: R) -> Result<(u32, usize), ReadError> {
+            lines(reader, |line| parse::numbers::<u32>(line, line))
+                .try_fold((0, 0), |(sum, count), numbers| Ok((sum + numbers?.iter().sum::<u32>(), count + 1)))
+        }
+    }
+
+    #[test]
+    fn parsed_lines() {
+        let input = "1 2\r\n\n  \n3 4 5\n6";
+        let numbers: Vec<Vec<u32>> = lines(Cursor::new(input), |line| parse::numbers(line, line))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(numbers, [vec![1, 2], vec![3, 4, 5], vec![6]]);
+
+        let input = "1 2\n\n3 x 5\n";
+        let err = lines(BufReader::with_capacity(2, input.as_bytes()), |line| parse::numbers::<u32>(line, line))
+            .find_map(Result::err);
+
+        match err {
+            Some(ReadError::Parse(err)) => assert_eq!(err, ParseError::new(3, 3, "x", "expected a number")),
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn solve_streamed() {
+        let input = "1 2\n3 4 5\n";
+        let solved = solve::<Sums>(&mut Cursor::new(input), None).unwrap();
+        let answers: Vec<_> = solved.answers.iter().map(|a| (a.part, a.value.as_str())).collect();
+
+        assert_eq!(answers, [(Part::One, "15"), (Part::Two, "2")]);
+
+        let solved = solve::<Sums>(&mut Cursor::new(input), Some(Part::Two)).unwrap();
+
+        assert_eq!(solved.answer(Part::Two).map(|a| a.value.as_str()), Some("2"));
+        assert_eq!(solved.answer(Part::One), None);
+
+        let err = solve::<Sums>(&mut Cursor::new("1\n-2\n"), None).unwrap_err();
+
+        assert_eq!(err.to_string(), "Could not parse input: line 2, column 1: expected a number at \"-2\"");
+    }
+}
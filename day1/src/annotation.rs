@@ -0,0 +1,154 @@
+//! Words found in a line and the ones its calibration value is made of
+//!
+//! An [`Annotation`] shows why a line gets the value it does, such as how part 1 and part 2 come
+//! to disagree on it, by listing the byte range and form of every word found in the line.
+
+use std::fmt::Write;
+
+use crate::scanner::{Form, Match, combine, first_and_last};
+
+/// Line with every word found in it, and the first and last of them if there are any
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Annotation<'a> {
+    pub line: &'a str,
+    /// Every word of the line, in the order they end
+    pub matches: Vec<Match>,
+    pub first: Option<Match>,
+    pub last: Option<Match>,
+}
+
+impl<'a> Annotation<'a> {
+    pub fn new(line: &'a str, matches: Vec<Match>) -> Self {
+        let (first, last) = first_and_last(matches.iter().copied()).unzip();
+
+        Self { line, matches, first, last }
+    }
+
+    /// Calibration value of the line, made of the values of its first and last words
+    pub fn value(&self) -> Option<u32> {
+        Some(combine(self.first?.value, self.last?.value))
+    }
+
+    /// Text of the line that a word was found at
+    pub fn text(&self, m: &Match) -> &'a str {
+        &self.line[m.span()]
+    }
+
+    /// Line with its first word in green, its last word in blue and the letters they share in
+    /// cyan, the other words being underlined
+    fn highlighted(&self) -> String {
+        let in_match = |m: Option<Match>, i: usize| m.is_some_and(|m| m.span().contains(&i));
+        let mut out = String::new();
+        let mut style = None;
+
+        for (i, c) in self.line.char_indices() {
+            let next = match (in_match(self.first, i), in_match(self.last, i)) {
+                (true, true) => Some(36),
+                (true, false) => Some(32),
+                (false, true) => Some(34),
+                _ if self.matches.iter().any(|m| m.span().contains(&i)) => Some(4),
+                _ => None,
+            };
+
+            if next != style {
+                if style.is_some() {
+                    out.push_str("\x1b[0m");
+                }
+
+                if let Some(code) = next {
+                    write!(out, "\x1b[{}m", code).unwrap();
+                }
+
+                style = next;
+            }
+
+            out.push(c);
+        }
+
+        if style.is_some() {
+            out.push_str("\x1b[0m");
+        }
+
+        out
+    }
+
+    /// Line followed by its value and a row per word with its byte range, its form, and whether it
+    /// is the first or last word, the chosen words being highlighted with ANSI colors if `color`
+    pub fn render(&self, color: bool) -> String {
+        let line = if color { self.highlighted() } else { self.line.to_string() };
+
+        let mut out = match self.value() {
+            Some(value) => format!("{} = {}\n", line, value),
+            None => format!("{} has no digit\n", line),
+        };
+
+        let width = self.matches.iter().map(|m| self.text(m).chars().count()).max().unwrap_or(0);
+
+        for m in &self.matches {
+            let form = match m.form {
+                Form::Digits => "digits",
+                Form::Word => "word",
+            };
+
+            let role = match (self.first == Some(*m), self.last == Some(*m)) {
+                (true, true) => "first, last",
+                (true, false) => "first",
+                (false, true) => "last",
+                (false, false) => "",
+            };
+
+            let span = format!("{}..{}", m.start, m.end);
+            let row = format!("  {:width$}  {:8}  {:6}  {}", self.text(m), span, form, role);
+
+            writeln!(out, "{}", row.trim_end()).unwrap();
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::scanner::{DIGITS, Form, Match, Scanner, Vocabulary};
+
+    #[test]
+    fn chosen_words() {
+        let scanner = Vocabulary::default().scanner();
+        let annotation = scanner.annotate("xtwone3four");
+
+        assert_eq!(annotation.first, Some(Match { value: 2, form: Form::Word, start: 1, end: 4 }));
+        assert_eq!(annotation.last, Some(Match { value: 4, form: Form::Word, start: 7, end: 11 }));
+        assert_eq!(annotation.value(), Some(24));
+        assert_eq!(annotation.text(&annotation.matches[1]), "one");
+
+        let digits = Scanner::new(DIGITS);
+        let annotations: Vec<_> = digits.annotations("xtwone3four\nabc\n").collect();
+
+        assert_eq!(annotations[0].first, annotations[0].last);
+        assert_eq!(annotations[0].value(), Some(33));
+        assert_eq!(annotations[1].first, None);
+        assert_eq!(annotations[1].value(), None);
+    }
+
+    #[test]
+    fn rendering() {
+        let scanner = Vocabulary::default().scanner();
+        let expected = indoc!{"
+            xtwone3four = 24
+              two   1..4      word    first
+              one   3..6      word
+              3     6..7      digits
+              four  7..11     word    last
+        "};
+
+        assert_eq!(scanner.annotate("xtwone3four").render(false), expected);
+        assert_eq!(scanner.annotate("abc").render(false), "abc has no digit\n");
+
+        let expected = "\x1b[32mtw\x1b[0m\x1b[36mo\x1b[0m\x1b[34mne\x1b[0m = 21\n";
+
+        assert!(scanner.annotate("twone").render(true).starts_with(expected));
+        assert!(scanner.annotate("1two3").render(true).starts_with("\x1b[32m1\x1b[0m\x1b[4mtwo\x1b[0m\x1b[34m3\x1b[0m"));
+    }
+}
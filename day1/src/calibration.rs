@@ -0,0 +1,111 @@
+//! Calibration values of every line of a document, along with the lines that have none
+//!
+//! A [`Report`] keeps the outcome of each line, and the document can then be summed strictly,
+//! failing at the first line without a value, or leniently, skipping and counting those lines.
+
+use aoc::{ParseError, parse};
+
+use crate::scanner::Scanner;
+
+/// Line of a document with its calibration value, or the reason it has none
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub text: &'a str,
+    pub value: Result<u32, ParseError>,
+}
+
+/// Outcome of every non-blank line of a document, in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<'a> {
+    pub lines: Vec<Line<'a>>,
+}
+
+/// Sum of the values of the valid lines of a document, and the number of lines skipped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lenient {
+    pub sum: u64,
+    pub skipped: usize,
+}
+
+impl<'a> Report<'a> {
+    /// Calibration value of every line of `doc`, using the words of `scanner`
+    pub fn new(scanner: &Scanner, doc: &'a str) -> Self {
+        let lines = parse::lines(doc)
+            .map(|text| Line {
+                text,
+                value: scanner
+                    .calibration(text)
+                    .ok_or_else(|| ParseError::at(doc, text, "no digit found")),
+            })
+            .collect();
+
+        Self { lines }
+    }
+
+    /// Lines with a calibration value, along with it
+    pub fn valid(&self) -> impl Iterator<Item = (&'a str, u32)> + '_ {
+        self.lines
+            .iter()
+            .filter_map(|line| line.value.as_ref().ok().map(|&value| (line.text, value)))
+    }
+
+    /// Errors of the lines without a calibration value, pointing at them
+    pub fn invalid(&self) -> impl Iterator<Item = &ParseError> {
+        self.lines.iter().filter_map(|line| line.value.as_ref().err())
+    }
+
+    /// Sum of every calibration value, failing at the first line without one
+    ///
+    /// Values of two words of a vocabulary reach 99,999,999, so the sum is taken in a `u64`.
+    pub fn strict(&self) -> Result<u64, ParseError> {
+        self.lines.iter().map(|line| line.value.clone().map(u64::from)).sum()
+    }
+
+    /// Sum of the calibration values of the lines that have one, skipping the others
+    pub fn lenient(&self) -> Lenient {
+        Lenient {
+            sum: self.valid().map(|(_, value)| u64::from(value)).sum(),
+            skipped: self.invalid().count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::ParseError;
+
+    use crate::scanner::Vocabulary;
+
+    use super::{Lenient, Report};
+
+    #[test]
+    fn strict_and_lenient() {
+        let scanner = Vocabulary::default().scanner();
+        let doc = "two1nine\nabc\n\nxtwone3four\n  nothing here\n";
+        let report = Report::new(&scanner, doc);
+
+        assert_eq!(report.valid().collect::<Vec<_>>(), [("two1nine", 29), ("xtwone3four", 24)]);
+        assert_eq!(report.invalid().collect::<Vec<_>>(), [
+            &ParseError::new(2, 1, "abc", "no digit found"),
+            &ParseError::new(5, 3, "nothing here", "no digit found"),
+        ]);
+
+        assert_eq!(report.strict(), Err(ParseError::new(2, 1, "abc", "no digit found")));
+        assert_eq!(report.lenient(), Lenient { sum: 53, skipped: 2 });
+
+        let report = Report::new(&scanner, "two1nine\nxtwone3four\n");
+
+        assert_eq!(report.strict(), Ok(53));
+        assert_eq!(report.lenient(), Lenient { sum: 53, skipped: 0 });
+    }
+
+    #[test]
+    fn large_values() {
+        let scanner = Vocabulary::new([("big", 9999)]).scanner();
+        let doc = "big\n".repeat(50);
+        let report = Report::new(&scanner, &doc);
+
+        assert_eq!(report.strict(), Ok(50 * 99_999_999));
+        assert_eq!(report.lenient(), Lenient { sum: 50 * 99_999_999, skipped: 0 });
+    }
+}
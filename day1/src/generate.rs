@@ -0,0 +1,54 @@
+//! Random calibration documents
+
+use aoc::Generate;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day1;
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// Letters, digits and spelled out digits, with at least one digit so that part 1 has a value
+fn line<R: Rng>(rng: &mut R) -> String {
+    let mut pieces: Vec<String> = (0..rng.gen_range(0..6))
+        .map(|_| match rng.gen_range(0..3) {
+            0 => rng.gen_range(1..=9).to_string(),
+            1 => WORDS.choose(rng).expect("Words are not empty").to_string(),
+            _ => (0..rng.gen_range(1..=4)).map(|_| rng.gen_range('a'..='z')).collect(),
+        })
+        .collect();
+
+    let digit = rng.gen_range(1..=9).to_string();
+    pieces.insert(rng.gen_range(0..=pieces.len()), digit);
+
+    pieces.concat()
+}
+
+impl Generate for Day1 {
+    /// Document of `size` lines
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        (0..size).map(|_| line(&mut rng) + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Generate;
+
+    use crate::Day1;
+
+    #[test]
+    fn generated() {
+        for seed in 0..20 {
+            let input = Day1::generate(seed, 100);
+
+            assert_eq!(input, Day1::generate(seed, 100));
+            assert_eq!(input.lines().count(), 100);
+            assert!(input.lines().all(|line| line.chars().any(|c| c.is_ascii_digit())));
+            aoc::solve::<Day1>(&input, None).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+        }
+    }
+}
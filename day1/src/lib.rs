@@ -0,0 +1,49 @@
+//! Day 1: Trebuchet?!
+
+pub mod annotation;
+pub mod calibration;
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod scanner;
+
+use std::io::BufRead;
+
+use aoc::stream::{self, ReadError, Stream};
+use aoc::{ParseError, Solution, parallel, parse};
+
+/// Lines without a digit have no calibration value and are left out of the sums, see
+/// [`calibration::Report`] to find them
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parse::lines(input).collect())
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> u32 {
+        parallel::sum(lines, |line| part1::parse_value(line).unwrap_or(0))
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> u32 {
+        parallel::sum(lines, |line| part2::parse_value(line).unwrap_or(0))
+    }
+}
+
+impl Stream for Day1 {
+    fn stream<R: BufRead>(reader: R) -> Result<(u32, u32), ReadError> {
+        let values = |line: &str| Ok((part1::parse_value(line).unwrap_or(0), part2::parse_value(line).unwrap_or(0)));
+
+        stream::lines(reader, values)
+            .try_fold((0, 0), |(sum1, sum2), values| {
+                let (value1, value2) = values?;
+                Ok((sum1 + value1, sum2 + value2))
+            })
+    }
+}
@@ -1,26 +1,17 @@
-fn parse_digit<Chars>(cs: Chars) -> u32
-where
-    Chars: Iterator<Item = char>
-{
-    for c in cs {
-        if c.is_numeric() {
-            return c.to_digit(10).expect("Could not parse digit");
-        }
-    }
+use std::sync::LazyLock;
 
-    panic!("No digit found")
-}
+use crate::calibration::Report;
+use crate::scanner::{DIGITS, Scanner};
 
-fn parse_value(line: &str) -> u32 {
-    let trimmed = line.trim();
-    let d1 = parse_digit(trimmed.chars());
-    let d2 = parse_digit(trimmed.chars().rev());
+static SCANNER: LazyLock<Scanner> = LazyLock::new(|| Scanner::new(DIGITS));
 
-    d1 * 10 + d2
+/// Calibration value of a line, made of its first and last digit, or `None` if it has no digit
+pub fn parse_value(line: &str) -> Option<u32> {
+    SCANNER.calibration(line)
 }
 
-pub fn sum(doc: &str) -> u32 {
-    doc.lines().map(parse_value).sum()
+pub fn sum(doc: &str) -> Report<'_> {
+    SCANNER.sum(doc)
 }
 
 #[cfg(test)]
@@ -35,6 +26,6 @@ mod tests {
 
     #[test]
     fn part1() {
-        assert_eq!(sum(TEST_LINES), 142);
+        assert_eq!(sum(TEST_LINES).strict(), Ok(142));
     }
 }
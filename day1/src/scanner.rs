@@ -0,0 +1,386 @@
+//! Digits found in a single pass over a line, whether written as digits or spelled out
+//!
+//! A [`Scanner`] is built once from a table of words and the values they stand for, and reports
+//! every occurrence of every word, including those sharing letters such as the `two` and `one` of
+//! `twone`. The table can be given as a [`Vocabulary`], read from a file with a `word = value`
+//! entry per line.
+
+use std::cmp::Reverse;
+use std::iter;
+use std::ops::Range;
+
+use aho_corasick::AhoCorasick;
+use aho_corasick::automaton::OverlappingState;
+use aoc::{ParseError, parse};
+
+use crate::annotation::Annotation;
+use crate::calibration::Report;
+
+/// Digits written with a single character
+pub const DIGITS: [(&str, u32); 9] = [
+    ("1", 1), ("2", 2), ("3", 3), ("4", 4), ("5", 5), ("6", 6), ("7", 7), ("8", 8), ("9", 9),
+];
+
+/// Digits spelled out with letters
+pub const WORDS: [(&str, u32); 9] = [
+    ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5),
+    ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9),
+];
+
+/// Largest value a word can stand for, so that the calibration value made of two of them fits in
+/// a `u32`
+pub const MAX_VALUE: u32 = 9999;
+
+/// Words to look for in a line and the values they stand for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    ignore_case: bool,
+}
+
+impl Vocabulary {
+    pub fn new<I, W>(table: I) -> Self
+    where
+        I: IntoIterator<Item = (W, u32)>,
+        W: Into<String>,
+    {
+        let words = table.into_iter().map(|(word, value)| (word.into(), value)).collect();
+
+        Self { words, ignore_case: false }
+    }
+
+    /// Also match words whose letters are in a different case, in any alphabet: `One` or `ONE` for
+    /// `one`, `ÉÉN` for `één`
+    ///
+    /// Words and lines are compared in lower case, as given by [`char::to_lowercase`].
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words.iter().map(|(word, value)| (word.as_str(), *value))
+    }
+
+    /// # Panics
+    ///
+    /// If a word stands for a value larger than [`MAX_VALUE`].
+    pub fn scanner(&self) -> Scanner {
+        Scanner::build(self.words(), self.ignore_case)
+    }
+}
+
+/// Digits and the words spelling them out, as in the puzzle
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::new(DIGITS.into_iter().chain(WORDS))
+    }
+}
+
+/// Vocabulary with a `word = value` entry per line, lines starting with `#` being comments
+///
+/// Values are at most [`MAX_VALUE`].
+impl TryFrom<&str> for Vocabulary {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let words = parse::lines(input)
+            .filter(|line| !line.starts_with('#'))
+            .map(|line| {
+                let (word, value) = line
+                    .split_once('=')
+                    .ok_or_else(|| ParseError::at(input, &line[line.len()..], "expected \"=\" followed by a value"))?;
+                let word = word.trim();
+
+                if word.is_empty() {
+                    return Err(ParseError::near(input, line, "expected a word"));
+                }
+
+                let value = value.trim();
+                let n = parse::number(input, value)?;
+
+                if n > MAX_VALUE {
+                    return Err(ParseError::at(input, value, format!("expected a value of at most {}", MAX_VALUE)));
+                }
+
+                Ok((word, n))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(words))
+    }
+}
+
+/// How a word of a vocabulary is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    /// Only made of digits, such as `7`
+    Digits,
+    /// Spelled out, such as `seven`
+    Word,
+}
+
+impl Form {
+    fn of(word: &str) -> Self {
+        if !word.is_empty() && word.bytes().all(|b| b.is_ascii_digit()) {
+            Form::Digits
+        } else {
+            Form::Word
+        }
+    }
+}
+
+/// Occurrence of a word in a line, `start..end` being its byte range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub value: u32,
+    pub form: Form,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Match {
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// Line with its letters in lower case, along with the byte of the line that each of its bytes
+/// comes from, followed by the length of the line
+fn lowercase(line: &str) -> (String, Vec<usize>) {
+    let mut text = String::with_capacity(line.len());
+    let mut origins = Vec::with_capacity(line.len() + 1);
+
+    for (i, c) in line.char_indices() {
+        for lower in c.to_lowercase() {
+            text.push(lower);
+            origins.extend(iter::repeat_n(i, lower.len_utf8()));
+        }
+    }
+
+    origins.push(line.len());
+
+    (text, origins)
+}
+
+/// Calibration value made of the values of the first and last words, their decimal digits being
+/// written one after the other: 1 and 2 give 12, 13 and 2 give 132, and 0 and 5 give 5
+pub(crate) fn combine(first: u32, last: u32) -> u32 {
+    let digits = last.checked_ilog10().unwrap_or(0) + 1;
+
+    first * 10u32.pow(digits) + last
+}
+
+/// Matches giving the calibration value of a line: the ones starting first and last, the longest
+/// of those starting at the same position, so that `sixteen` is not read as `six`
+pub(crate) fn first_and_last(mut matches: impl Iterator<Item = Match>) -> Option<(Match, Match)> {
+    let m = matches.next()?;
+
+    Some(matches.fold((m, m), |(first, last), m| {
+        let before = (m.start, Reverse(m.end)) < (first.start, Reverse(first.end));
+        let after = (m.start, m.end) > (last.start, last.end);
+
+        (if before { m } else { first }, if after { m } else { last })
+    }))
+}
+
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    automaton: AhoCorasick,
+    values: Vec<u32>,
+    forms: Vec<Form>,
+    ignore_case: bool,
+}
+
+impl Scanner {
+    /// Scanner looking for every word of `table`, each standing for its value
+    ///
+    /// # Panics
+    ///
+    /// If a word stands for a value larger than [`MAX_VALUE`], or the table is too large to build
+    /// an automaton for.
+    pub fn new<I, W>(table: I) -> Self
+    where
+        I: IntoIterator<Item = (W, u32)>,
+        W: AsRef<str>,
+    {
+        Self::build(table, false)
+    }
+
+    fn build<I, W>(table: I, ignore_case: bool) -> Self
+    where
+        I: IntoIterator<Item = (W, u32)>,
+        W: AsRef<str>,
+    {
+        let (words, values): (Vec<W>, Vec<u32>) = table.into_iter().unzip();
+        assert!(values.iter().all(|&value| value <= MAX_VALUE), "Words stand for values of at most {}", MAX_VALUE);
+
+        let forms = words.iter().map(|word| Form::of(word.as_ref())).collect();
+        let words = words.iter().map(|word| {
+            if ignore_case { word.as_ref().to_lowercase() } else { word.as_ref().to_string() }
+        });
+        let automaton = AhoCorasick::new(words).expect("Words can be searched for");
+
+        Self { automaton, values, forms, ignore_case }
+    }
+
+    /// Every occurrence of a word in `line`, including overlapping ones, in the order they end
+    ///
+    /// When ignoring case, the words are looked for in the line in lower case, and their byte
+    /// ranges are those of the letters they were found at in `line`.
+    pub fn matches<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match> + 'a {
+        let lower = self.ignore_case.then(|| lowercase(line));
+        let mut state = OverlappingState::start();
+
+        iter::from_fn(move || {
+            let haystack = lower.as_ref().map_or(line, |(text, _)| text.as_str());
+            let origin = |i: usize| lower.as_ref().map_or(i, |(_, origins)| origins[i]);
+
+            self.automaton.find_overlapping(haystack, &mut state);
+
+            state.get_match().map(|m| Match {
+                value: self.values[m.pattern()],
+                form: self.forms[m.pattern()],
+                start: origin(m.start()),
+                end: origin(m.end()),
+            })
+        })
+    }
+
+    /// Values of the words starting first and last in `line` written one after the other, see
+    /// [`combine`], or `None` if there are no words in the line
+    pub fn calibration(&self, line: &str) -> Option<u32> {
+        let (first, last) = first_and_last(self.matches(line))?;
+
+        Some(combine(first.value, last.value))
+    }
+
+    /// Every match of `line` along with the ones its calibration value is made of
+    pub fn annotate<'a>(&self, line: &'a str) -> Annotation<'a> {
+        Annotation::new(line, self.matches(line).collect())
+    }
+
+    /// Annotation of every non-blank line of `doc`
+    pub fn annotations<'a>(&'a self, doc: &'a str) -> impl Iterator<Item = Annotation<'a>> + 'a {
+        parse::lines(doc).map(|line| self.annotate(line))
+    }
+
+    /// Calibration value of every line of `doc`, or the reason a line has none
+    pub fn sum<'a>(&self, doc: &'a str) -> Report<'a> {
+        Report::new(self, doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::ParseError;
+    use indoc::indoc;
+
+    use super::{DIGITS, Form, Match, Scanner, Vocabulary, WORDS, combine};
+
+    #[test]
+    fn overlapping_matches() {
+        let scanner = Scanner::new(DIGITS.into_iter().chain(WORDS));
+        let matches: Vec<Match> = scanner.matches("xtwone3").collect();
+
+        assert_eq!(matches, [
+            Match { value: 2, form: Form::Word, start: 1, end: 4 },
+            Match { value: 1, form: Form::Word, start: 3, end: 6 },
+            Match { value: 3, form: Form::Digits, start: 6, end: 7 },
+        ]);
+
+        assert_eq!(scanner.calibration("eightwo"), Some(82));
+        assert_eq!(scanner.calibration("nine"), Some(99));
+        assert_eq!(scanner.calibration("xyz"), None);
+    }
+
+    #[test]
+    fn custom_table() {
+        let scanner = Scanner::new([("bone", 4), ("one", 1), ("x", 0)]);
+
+        assert_eq!(scanner.calibration("boneone"), Some(41));
+        assert_eq!(scanner.calibration("bone"), Some(41));
+        assert_eq!(scanner.calibration("x1one"), Some(1));
+    }
+
+    #[test]
+    fn teens() {
+        let input = indoc!{"
+            zero = 0
+            two = 2
+            thirteen = 13
+            twelve = 12
+        "};
+
+        let scanner = Vocabulary::try_from(input).unwrap().scanner();
+
+        assert_eq!(scanner.calibration("thirteen and two"), Some(132));
+        assert_eq!(scanner.calibration("two and thirteen"), Some(213));
+        assert_eq!(scanner.calibration("twelve"), Some(1212));
+        assert_eq!(scanner.calibration("zero two"), Some(2));
+        assert_eq!(scanner.annotate("thirteen and two").value(), Some(132));
+        assert_eq!(combine(9999, 9999), 99_999_999);
+        assert_eq!(combine(4, 0), 40);
+
+        let scanner = Scanner::new([("six", 6), ("sixteen", 16), ("four", 4), ("fourteen", 14)]);
+
+        assert_eq!(scanner.calibration("sixteen"), Some(1616));
+        assert_eq!(scanner.calibration("a fourteen"), Some(1414));
+        assert_eq!(scanner.calibration("six fourteen"), Some(614));
+        assert_eq!(scanner.calibration("fourteen and six"), Some(146));
+        assert_eq!(scanner.annotate("sixteen").first, Some(Match { value: 16, form: Form::Word, start: 0, end: 7 }));
+    }
+
+    #[test]
+    fn other_alphabets() {
+        let input = indoc!{"
+            één = 1
+            zwölf = 12
+            два = 2
+            straße = 7
+        "};
+
+        let vocabulary = Vocabulary::try_from(input).unwrap();
+        let scanner = vocabulary.clone().ignore_case(true).scanner();
+
+        assert_eq!(vocabulary.scanner().calibration("ÉÉN ZWÖLF"), None);
+        assert_eq!(scanner.calibration("ÉÉN ZWÖLF"), Some(112));
+        assert_eq!(scanner.calibration("Два и Één"), Some(21));
+
+        // The capital ẞ takes one more byte than the ß it stands for
+        let annotation = scanner.annotate("xSTRAẞE, Zwölf");
+
+        assert_eq!(annotation.value(), Some(712));
+        assert_eq!(annotation.text(&annotation.matches[0]), "STRAẞE");
+        assert_eq!(annotation.text(&annotation.matches[1]), "Zwölf");
+    }
+
+    #[test]
+    fn vocabularies() {
+        let input = indoc!{"
+            # Dutch
+            een = 1
+            twee = 2
+            drie = 3
+        "};
+
+        let vocabulary = Vocabulary::try_from(input).unwrap();
+
+        assert_eq!(vocabulary.words().collect::<Vec<_>>(), [("een", 1), ("twee", 2), ("drie", 3)]);
+        assert_eq!(vocabulary.scanner().sum("tweeeen\nxdrie\n").strict(), Ok(21 + 33));
+        assert_eq!(vocabulary.scanner().calibration("Drie een"), Some(11));
+        assert_eq!(vocabulary.ignore_case(true).scanner().calibration("Drie een"), Some(31));
+        assert_eq!(Vocabulary::default().scanner().sum("two1nine\nxtwone3four\n").strict(), Ok(29 + 24));
+
+        assert_eq!(
+            Vocabulary::try_from("een = 1\ntwee 2\n"),
+            Err(ParseError::new(2, 7, "", "expected \"=\" followed by a value")),
+        );
+        assert_eq!(Vocabulary::try_from("een = 1\n = 2\n"), Err(ParseError::new(2, 2, "=", "expected a word")));
+        assert_eq!(
+            Vocabulary::try_from("een = 1\nlots = 10000\n"),
+            Err(ParseError::new(2, 8, "10000", "expected a value of at most 9999")),
+        );
+        assert_eq!(Vocabulary::try_from("een = one\n"), Err(ParseError::new(1, 7, "one", "expected a number")));
+    }
+}
@@ -0,0 +1,191 @@
+//! Random pipe mazes
+
+use aoc::Generate;
+use grid::{Grid, Position};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day10;
+
+const UP: u8 = 1;
+const RIGHT: u8 = 2;
+const DOWN: u8 = 4;
+const LEFT: u8 = 8;
+
+const JUNK: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+fn pipe(directions: u8) -> char {
+    match directions {
+        d if d == UP | DOWN => '|',
+        d if d == LEFT | RIGHT => '-',
+        d if d == DOWN | RIGHT => 'F',
+        d if d == DOWN | LEFT => '7',
+        d if d == UP | RIGHT => 'L',
+        d if d == UP | LEFT => 'J',
+        _ => unreachable!("Loop tiles have two directions"),
+    }
+}
+
+/// Random spanning tree of an `n` by `n` grid, as whether each node links to its right and below
+fn spanning_tree<R: Rng>(rng: &mut R, n: usize) -> (Grid<bool>, Grid<bool>) {
+    let mut right = Grid::from_cells(n, vec![false; n * n]);
+    let mut down = Grid::from_cells(n, vec![false; n * n]);
+    let mut visited = Grid::from_cells(n, vec![false; n * n]);
+    let mut stack = vec![Position::new(0, 0)];
+    visited[Position::new(0, 0)] = true;
+
+    while let Some(&node) = stack.last() {
+        let unvisited: Vec<Position> = visited
+            .neighbours4(node)
+            .filter(|&p| !visited[p])
+            .collect();
+
+        let Some(&next) = unvisited.choose(rng) else {
+            stack.pop();
+            continue;
+        };
+
+        match (next.row.cmp(&node.row), next.col.cmp(&node.col)) {
+            (_, std::cmp::Ordering::Greater) => right[node] = true,
+            (_, std::cmp::Ordering::Less) => right[next] = true,
+            (std::cmp::Ordering::Greater, _) => down[node] = true,
+            _ => down[next] = true,
+        }
+
+        visited[next] = true;
+        stack.push(next);
+    }
+
+    (right, down)
+}
+
+/// Loop through every tile of a `2n` by `2n` grid, as the directions each tile connects to
+///
+/// Every node of a spanning tree becomes a square of four tiles, and the loop goes round the
+/// outline of the tree.
+fn tree_loop<R: Rng>(rng: &mut R, n: usize) -> Grid<u8> {
+    let (right, down) = spanning_tree(rng, n);
+    let mut tiles = Grid::from_cells(2 * n, vec![0; 4 * n * n]);
+
+    for node in right.positions() {
+        let (row, col) = (2 * node.row, 2 * node.col);
+
+        tiles[Position::new(row, col)] |= RIGHT | DOWN;
+        tiles[Position::new(row, col + 1)] |= LEFT | DOWN;
+        tiles[Position::new(row + 1, col)] |= UP | RIGHT;
+        tiles[Position::new(row + 1, col + 1)] |= UP | LEFT;
+    }
+
+    for node in right.positions() {
+        let (row, col) = (2 * node.row, 2 * node.col);
+
+        if right[node] {
+            tiles[Position::new(row, col + 1)] ^= DOWN | RIGHT;
+            tiles[Position::new(row + 1, col + 1)] ^= UP | RIGHT;
+            tiles[Position::new(row, col + 2)] ^= DOWN | LEFT;
+            tiles[Position::new(row + 1, col + 2)] ^= UP | LEFT;
+        }
+
+        if down[node] {
+            tiles[Position::new(row + 1, col)] ^= RIGHT | DOWN;
+            tiles[Position::new(row + 1, col + 1)] ^= LEFT | DOWN;
+            tiles[Position::new(row + 2, col)] ^= RIGHT | UP;
+            tiles[Position::new(row + 2, col + 1)] ^= LEFT | UP;
+        }
+    }
+
+    tiles
+}
+
+impl Generate for Day10 {
+    /// Maze of `4 * size + 1` tiles square, whose loop is `8 * size²` tiles long
+    ///
+    /// The loop is spread out over every other tile, so it encloses `4 * size² - 3` tiles. Tiles
+    /// that are not part of it hold random pipes, except next to the start where they are ground,
+    /// so that only the loop connects to the start.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let n = size.max(1);
+        let tiles = tree_loop(&mut rng, n);
+        let width = 4 * n + 1;
+        let mut maze = Grid::from_cells(width, vec![None; width * width]);
+
+        for (position, &directions) in tiles.iter() {
+            let spread = Position::new(2 * position.row + 1, 2 * position.col + 1);
+            maze[spread] = Some(pipe(directions));
+
+            if directions & RIGHT != 0 {
+                maze[Position::new(spread.row, spread.col + 1)] = Some('-');
+            }
+
+            if directions & DOWN != 0 {
+                maze[Position::new(spread.row + 1, spread.col)] = Some('|');
+            }
+        }
+
+        let loop_tiles: Vec<Position> = maze
+            .iter()
+            .filter(|(_, tile)| tile.is_some())
+            .map(|(position, _)| position)
+            .collect();
+
+        let start = *loop_tiles.choose(&mut rng).expect("Loop is not empty");
+        let near_start: Vec<Position> = maze.neighbours4(start).collect();
+        maze[start] = Some('S');
+
+        let maze = maze.iter().map(|(position, &tile)| match tile {
+            Some(pipe) => pipe,
+            None if near_start.contains(&position) => '.',
+            None => *JUNK.choose(&mut rng).expect("Junk is not empty"),
+        });
+
+        let tiles: Vec<char> = maze.collect();
+
+        tiles
+            .chunks(width)
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Generate, Solution};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::Day10;
+
+    use super::{DOWN, LEFT, RIGHT, UP, tree_loop};
+
+    #[test]
+    fn loop_through_every_tile() {
+        let tiles = tree_loop(&mut ChaCha8Rng::seed_from_u64(10), 4);
+
+        for (position, &directions) in tiles.iter() {
+            assert_eq!(directions.count_ones(), 2, "{:?}", position);
+
+            if directions & RIGHT != 0 {
+                assert_ne!(tiles[position.right().unwrap()] & LEFT, 0, "{:?}", position);
+            }
+
+            if directions & DOWN != 0 {
+                assert_ne!(tiles[position.down().unwrap()] & UP, 0, "{:?}", position);
+            }
+        }
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            for size in 1..=5 {
+                let input = Day10::generate(seed, size);
+                let pipes = Day10::parse(&input).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+
+                assert_eq!(input, Day10::generate(seed, size));
+                assert_eq!(Day10::part1(&pipes), 4 * size * size, "Seed {}, size {}", seed, size);
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Day 10: Pipe Maze
+
+pub mod generate;
+pub mod pipes;
+
+use aoc::{ParseError, Solution};
+
+use pipes::Pipes;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input<'a> = Pipes;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Pipes::try_from(input)
+    }
+
+    fn part1(pipes: &Self::Input<'_>) -> usize {
+        pipes.max_steps()
+    }
+
+    fn part2(pipes: &Self::Input<'_>) -> usize {
+        pipes.contained_tiles().len()
+    }
+}
@@ -1,39 +1,22 @@
 use std::collections::{HashMap, HashSet};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Position {
-    row: i64,
-    col: i64,
-}
-
-impl Position {
-    fn up(&self) -> Self {
-        Position {
-            row: self.row - 1,
-            col: self.col,
-        }
-    }
-
-    fn down(&self) -> Self {
-        Position {
-            row: self.row + 1,
-            col: self.col,
-        }
-    }
-
-    fn left(&self) -> Self {
-        Position {
-            row: self.row,
-            col: self.col - 1,
-        }
-    }
-
-    fn right(&self) -> Self {
-        Position {
-            row: self.row,
-            col: self.col + 1,
-        }
-    }
+use aoc::ParseError;
+use grid::render::{Color, Picture};
+use grid::{Grid, Position};
+
+/// Positions connected by a tile of the map, or `None` if the tile is not a pipe
+fn pipe_ends(tile: char, pos: Position) -> Option<HashSet<Position>> {
+    let ends = match tile {
+        '|' => [pos.up(), pos.down()],
+        '-' => [pos.left(), pos.right()],
+        'F' => [pos.right(), pos.down()],
+        '7' => [pos.left(), pos.down()],
+        'L' => [pos.up(), pos.right()],
+        'J' => [pos.up(), pos.left()],
+        _ => return None,
+    };
+
+    Some(ends.into_iter().flatten().collect())
 }
 
 struct Pipe {
@@ -49,102 +32,50 @@ struct Pipe {
 ///
 /// Given a map like so:
 ///
+/// ```text
 ///        12345
 ///        .....1
 ///        .S-7.2
 ///        .|.|.3
 ///        .L-J.4
 ///        .....5
+/// ```
 ///
 /// When starting at the S at (2,2) the reachable positions are { (2,3), (3,2) }. We can then
 /// compute the loop by expanding the reachable positions in either direction until we find
 /// encounter repeat positions.
 #[derive(Debug, PartialEq, Eq)]
-pub struct Pipes<'a> { 
+pub struct Pipes {
     start: Position,
     connections: HashMap<Position, HashSet<Position>>,  // positions touching an end
-    map: &'a str
+    map: Grid<char>,
 }
 
-impl<'a> From<&'a str> for Pipes<'a> {
-    fn from(input: &'a str) -> Self {
-        let mut start: Option<Position> = None;
-        let map_line = |(row, line): (usize, &str)| -> std::vec::IntoIter<Pipe> {
-            let mut pipes = Vec::new();
-
-            for (col, c) in line.trim().chars().enumerate() {
-                let pos = Position {
-                    row: row.try_into().expect("Could not convert row to i64"),
-                    col: col.try_into().expect("Could not convert col to i64"),
-                };
-
-                match c {
-                    '|' => {
-                        let pipe = Pipe {
-                            ends: [pos.up(), pos.down()].into(),
-                            position: pos,
-                        };
-
-                        pipes.push(pipe);
-                    },
-                    '-' => {
-                        let pipe = Pipe {
-                            ends: [pos.left(), pos.right()].into(),
-                            position: pos,
-                        };
-
-                        pipes.push(pipe);
-                    },
-                    'F' => {
-                        let pipe = Pipe {
-                            ends: [pos.right(), pos.down()].into(),
-                            position: pos,
-                        };
-
-                        pipes.push(pipe);
-                    },
-                    '7' => {
-                        let pipe = Pipe {
-                            ends: [pos.left(), pos.down()].into(),
-                            position: pos,
-                        };
-
-                        pipes.push(pipe);
-                    },
-                    'L' => {
-                        let pipe = Pipe {
-                            ends: [pos.up(), pos.right()].into(),
-                            position: pos,
-                        };
-
-                        pipes.push(pipe);
-                    },
-                    'J' => {
-                        let pipe = Pipe {
-                            ends: [pos.up(), pos.left()].into(),
-                            position: pos,
-                        };
-
-                        pipes.push(pipe);
-                    },
-                    'S' => {
-                        start = Some(pos);
-                    },
-                    _ => {},
-                };
-
-
-            }
-
-            pipes.into_iter()
-        };
+impl TryFrom<&str> for Pipes {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut has_start = false;
+        let map = Grid::parse(input, |c| match c {
+            'S' if has_start => Err("more than one start position"),
+            'S' => {
+                has_start = true;
+                Ok(c)
+            },
+            // The examples in the puzzle mark tiles inside and outside the loop with I and O
+            '|' | '-' | 'F' | '7' | 'L' | 'J' | '.' | 'I' | 'O' => Ok(c),
+            _ => Err("unknown tile"),
+        })?;
+
+        let start = map
+            .iter()
+            .find(|(_, &c)| c == 'S')
+            .map(|(pos, _)| pos)
+            .ok_or_else(|| ParseError::at(input, &input[input.len()..], "no start position"))?;
 
-        let pipes: Vec<_> = input
-            .trim()
-            .lines()
-            .enumerate()
-            .map(map_line)
-            .flatten()
+        let pipes: Vec<_> = map
+            .iter()
+            .filter_map(|(position, &c)| Some(Pipe { ends: pipe_ends(c, position)?, position }))
             .collect();
 
         let mut connections: HashMap<_, _> = pipes
@@ -165,8 +96,7 @@ impl<'a> From<&'a str> for Pipes<'a> {
             })
             .collect();
 
-        let start = start.expect("No start position detected");
-        let start_connections = pipes
+        let start_connections: HashSet<_> = pipes
             .iter()
             .filter_map(|pipe| {
                 if pipe.ends.contains(&start) {
@@ -177,47 +107,41 @@ impl<'a> From<&'a str> for Pipes<'a> {
             })
             .collect();
 
-        connections.insert(start, start_connections);
-
-        Self { start, connections, map: input }
-    }
-}
+        // Tiles are checked by the grid, so the only S of the input is the start
+        let offset = input.find('S').expect("The start is in the input");
+        let start_tile = &input[offset..offset + 1];
 
-#[derive(Clone)]
-struct Path {
-    position: Position,
-    seen: HashSet<Position>,
-}
-
-impl Path {
-    fn start(p: Position) -> Self {
-        Self {
-            position: p.clone(),
-            seen: HashSet::new(),
+        if start_connections.len() != 2 {
+            let msg = format!("expected 2 pipes connecting to the start, found {}", start_connections.len());
+            return Err(ParseError::at(input, start_tile, msg));
         }
-    }
 
-    fn step(&self, p: Position) -> Self {
-        let mut seen = self.seen.clone();
-        seen.insert(self.position);
+        connections.insert(start, start_connections);
+
+        let pipes = Self { start, connections, map };
 
-        Self {
-            position: p,
-            seen,
+        if pipes.trace_loop().is_none() {
+            return Err(ParseError::at(input, start_tile, "no loop through the start"));
         }
+
+        Ok(pipes)
     }
 }
 
-impl<'a> Pipes<'a> {
-    fn find_loop(&self) -> HashSet<Position> {
+impl Pipes {
+    /// Positions of every pipe that is part of the loop through the start
+    pub fn find_loop(&self) -> HashSet<Position> {
+        self.trace_loop().expect("Parsed maps have a loop through the start")
+    }
+
+    /// Positions of the loop through the start, or `None` if following the pipes from the start
+    /// leads to a dead end
+    fn trace_loop(&self) -> Option<HashSet<Position>> {
         let mut loop_positions = HashSet::new();
         loop_positions.insert(self.start);
 
-        let mut position = self.connections[&self.start]
-            .iter()
-            .copied()
-            .next()
-            .expect("No position connects to start");
+        let first = self.connections[&self.start].iter().copied().next()?;
+        let mut position = first;
 
         while position != self.start {
             let next_position = self.connections[&position]
@@ -232,18 +156,22 @@ impl<'a> Pipes<'a> {
                     position = p;
                 },
                 None => {
-                    if self.connections[&self.start].contains(&position) {
+                    if position != first && self.connections[&self.start].contains(&position) {
                         loop_positions.insert(position);
                         position = self.start;
                     } else {
-                        panic!("No next position found for {:?}", position);
+                        return None;
                     }
                 }
             }
 
         }
 
-        loop_positions
+        Some(loop_positions)
+    }
+
+    pub fn start(&self) -> Position {
+        self.start
     }
 
     pub fn max_steps(&self) -> usize {
@@ -254,78 +182,59 @@ impl<'a> Pipes<'a> {
         let loop_ = self.find_loop();
         let mut contained = HashSet::new();
 
-        for (row, line) in self.map.trim().lines().enumerate() {
-            let mut in_region = false;
-            let mut last_corner = ' ';
+        let mut in_region = false;
+        let mut last_corner = ' ';
 
-            for (col, mut c) in line.trim().chars().enumerate() {
-                let p = Position {
-                    row: row.try_into().expect("Could not convert row to i64"),
-                    col: col.try_into().expect("Could not convert col to i64"),
-                };
+        for (p, &tile) in self.map.iter() {
+            if p.col == 0 {
+                in_region = false;
+                last_corner = ' ';
+            }
 
-                if "|-S7JFL".contains(c) && !loop_.contains(&p) {
-                    continue;
-                }
+            let mut c = tile;
 
-                if c == 'S' {
-                    let connections = &self.connections[&p];
-                    
-                    if *connections == HashSet::from([p.left(), p.right()]) {
-                        c = '-';
-                    }
+            if "|-S7JFL".contains(c) && !loop_.contains(&p) {
+                continue;
+            }
 
-                    if *connections == HashSet::from([p.up(), p.down()]) {
-                        c = '|';
-                    }
+            if c == 'S' {
+                let connections = Some(&self.connections[&p]);
 
-                    if *connections == HashSet::from([p.right(), p.down()]) {
-                        c = 'F';
-                    }
+                c = "-|F7JL"
+                    .chars()
+                    .find(|&pipe| pipe_ends(pipe, p).as_ref() == connections)
+                    .unwrap_or(c);
+            }
 
-                    if *connections == HashSet::from([p.left(), p.down()]) {
-                        c = '7';
+            match c {
+                '|' => {
+                    in_region = !in_region;
+                },
+                '-' => {},
+                'S' => {},
+                '7' => {
+                    if last_corner == 'L' {
+                        in_region = !in_region;
                     }
 
-                    if *connections == HashSet::from([p.left(), p.up()]) {
-                        c = 'J';
+                    last_corner = '7';
+                },
+                'J' => {
+                    if last_corner == 'F' {
+                        in_region = !in_region;
                     }
 
-                    if *connections == HashSet::from([p.right(), p.up()]) {
-                        c = 'L';
-                    }
+                    last_corner = 'J';
+                },
+                'F' => {
+                    last_corner = 'F';
+                },
+                'L' => {
+                    last_corner = 'L';
                 }
-
-                match c {
-                    '|' => {
-                        in_region = !in_region;
-                    },
-                    '-' => {},
-                    'S' => {},
-                    '7' => {
-                        if last_corner == 'L' {
-                            in_region = !in_region;
-                        }
-
-                        last_corner = '7';
-                    },
-                    'J' => {
-                        if last_corner == 'F' {
-                            in_region = !in_region;
-                        }
-
-                        last_corner = 'J';
-                    },
-                    'F' => {
-                        last_corner = 'F';
-                    },
-                    'L' => {
-                        last_corner = 'L';
-                    }
-                    _ => {
-                        if in_region {
-                            contained.insert(p);
-                        }
+                _ => {
+                    if in_region {
+                        contained.insert(p);
                     }
                 }
             }
@@ -333,17 +242,28 @@ impl<'a> Pipes<'a> {
 
         contained
     }
+
+    /// Map with the loop, the tiles it encloses and the start highlighted
+    pub fn picture(&self) -> Picture {
+        Picture::new("Loop and enclosed tiles", self.map.clone())
+            .with_layer("loop", Color::Blue, self.find_loop())
+            .with_layer("enclosed", Color::Green, self.contained_tiles())
+            .with_layer("start", Color::Yellow, [self.start])
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::{HashMap, HashSet};
 
+    use aoc::ParseError;
+    use grid::render::Color;
+    use grid::{Grid, Position};
     use indoc::indoc;
 
-    use super::{Pipes, Position};
+    use super::Pipes;
 
-    fn pipes() -> Pipes<'static> {
+    fn pipes() -> Pipes {
         let start = Position { row: 1, col: 1 };
         let connections: HashMap<Position, HashSet<Position>> = HashMap::from([
             (Position { row: 1, col: 1 }, [Position { row: 1, col: 2 }, Position { row: 2, col: 1 }].into()),
@@ -356,15 +276,15 @@ mod tests {
             (Position { row: 3, col: 3 }, [Position { row: 3, col: 2 }, Position { row: 2, col: 3 }].into()),
         ]);
 
-        let map = indoc!{"
+        let map = Grid::try_from(indoc!{"
             .....
             .S-7.
             .|.|.
             .L-J.
             .....
-        "};
+        "});
 
-        Pipes { start, connections, map }
+        Pipes { start, connections, map: map.unwrap() }
     }
 
     #[test]
@@ -377,7 +297,7 @@ mod tests {
             .....
         "};
 
-        let parsed = Pipes::from(input);
+        let parsed = Pipes::try_from(input).unwrap();
         let expected = pipes();
 
         assert_eq!(parsed.start, expected.start);
@@ -385,6 +305,43 @@ mod tests {
         for (pos, expected_connections) in expected.connections {
             assert_eq!(parsed.connections[&pos], expected_connections, "Position {:?} does not match", pos);
         }
+
+        let input = indoc!{"
+            .....
+            .S-7.
+            .|x|.
+        "};
+
+        assert_eq!(Pipes::try_from(input), Err(ParseError::new(3, 3, "x", "unknown tile")));
+
+        let input = indoc!{"
+            .F-7.
+            .|.|.
+            .L-J.
+        "};
+
+        assert_eq!(Pipes::try_from(input), Err(ParseError::new(4, 1, "", "no start position")));
+
+        let input = indoc!{"
+            .....
+            .S-7.
+            ...|.
+            .L-J.
+        "};
+
+        assert_eq!(
+            Pipes::try_from(input),
+            Err(ParseError::new(2, 2, "S", "expected 2 pipes connecting to the start, found 1")),
+        );
+
+        let input = indoc!{"
+            .....
+            .S-7.
+            .|.|.
+            ...J.
+        "};
+
+        assert_eq!(Pipes::try_from(input), Err(ParseError::new(2, 2, "S", "no loop through the start")));
     }
 
     #[test]
@@ -415,7 +372,7 @@ mod tests {
             L|-JF
         "};
 
-        assert_eq!(Pipes::from(input).max_steps(), 4);
+        assert_eq!(Pipes::try_from(input).unwrap().max_steps(), 4);
 
         let input = indoc!{"
             ..F7.
@@ -425,7 +382,7 @@ mod tests {
             LJ...
         "};
 
-        assert_eq!(Pipes::from(input).max_steps(), 8);
+        assert_eq!(Pipes::try_from(input).unwrap().max_steps(), 8);
 
         let input = indoc!{"
             7-F7-
@@ -435,7 +392,7 @@ mod tests {
             LJ.LJ
         "};
 
-        assert_eq!(Pipes::from(input).max_steps(), 8);
+        assert_eq!(Pipes::try_from(input).unwrap().max_steps(), 8);
     }
 
     #[test]
@@ -461,7 +418,7 @@ mod tests {
             Position { row: 6, col: 8 },
         ]);
 
-        assert_eq!(Pipes::from(input).contained_tiles(), expected, "input1");
+        assert_eq!(Pipes::try_from(input).unwrap().contained_tiles(), expected, "input1");
 
         let input = indoc!{"
             .F----7F7F7F7F-7....
@@ -487,7 +444,7 @@ mod tests {
             Position { row: 6, col: 14 },
         ]);
 
-        assert_eq!(Pipes::from(input).contained_tiles(), expected, "input2");
+        assert_eq!(Pipes::try_from(input).unwrap().contained_tiles(), expected, "input2");
 
         let input = indoc!{"
             FF7FSF7F7F7F7F7F---7
@@ -527,6 +484,16 @@ mod tests {
         //   Position { row: 9, col: 18 },
         // }
 
-        assert_eq!(Pipes::from(input).contained_tiles(), expected, "input3");
+        assert_eq!(Pipes::try_from(input).unwrap().contained_tiles(), expected, "input3");
+    }
+
+    #[test]
+    fn picture() {
+        let picture = pipes().picture();
+
+        assert_eq!(picture.color(Position { row: 1, col: 1 }), Some(Color::Yellow));
+        assert_eq!(picture.color(Position { row: 3, col: 2 }), Some(Color::Blue));
+        assert_eq!(picture.color(Position { row: 2, col: 2 }), Some(Color::Green));
+        assert_eq!(picture.color(Position { row: 0, col: 0 }), None);
     }
 }
@@ -0,0 +1,51 @@
+//! Random images of the sky
+
+use aoc::Generate;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day11;
+
+impl Generate for Day11 {
+    /// Image of `size` by `size` pixels, where about a tenth of the rows and columns are empty
+    ///
+    /// Galaxies cover a twentieth of the rest of the image.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let empty_rows: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+        let empty_cols: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+
+        empty_rows
+            .iter()
+            .map(|&empty_row| {
+                let row: String = empty_cols
+                    .iter()
+                    .map(|&empty_col| {
+                        if !empty_row && !empty_col && rng.gen_bool(0.05) { '#' } else { '.' }
+                    })
+                    .collect();
+
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Generate, Solution};
+
+    use crate::Day11;
+
+    #[test]
+    fn generated() {
+        for seed in 0..20 {
+            let input = Day11::generate(seed, 60);
+            let galaxies = Day11::parse(&input).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+
+            assert_eq!(input, Day11::generate(seed, 60));
+            assert!(input.lines().all(|line| line.len() == 60));
+            assert!(Day11::part2(&galaxies) >= Day11::part1(&galaxies));
+        }
+    }
+}
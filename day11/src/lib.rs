@@ -0,0 +1,345 @@
+//! Day 11: Cosmic Expansion
+
+pub mod generate;
+
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use aoc::{ParseError, Solution};
+use grid::render::{Color, Picture};
+use grid::{Grid, Position};
+
+/// Position of a galaxy in the image
+pub type Galaxy = Position;
+
+pub fn read_galaxies(input: &str) -> Result<Vec<Galaxy>, ParseError> {
+    let image = Grid::parse(input, |c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err("expected '#' or '.'"),
+    })?;
+
+    let galaxies = image
+        .iter()
+        .filter(|(_, &is_galaxy)| is_galaxy)
+        .map(|(position, _)| position)
+        .collect();
+
+    Ok(galaxies)
+}
+
+/// Insert `amount` extra rows and columns in place of every empty row and column
+pub fn expand_galaxies(galaxies: &[Galaxy], amount: usize) -> Vec<Galaxy> {
+    let mut nrows = 0;
+    let mut occupied_rows = HashSet::new();
+
+    let mut ncols = 0;
+    let mut occupied_cols = HashSet::new();
+
+    for g in galaxies {
+        if g.row > nrows {
+            nrows = g.row;
+        }
+
+        occupied_rows.insert(g.row);
+
+        if g.col > ncols {
+            ncols = g.col;
+        }
+
+        occupied_cols.insert(g.col);
+    }
+
+    let empty_rows = (0..=nrows)
+        .filter(|row| !occupied_rows.contains(row))
+        .collect::<HashSet<_>>();
+
+    let empty_cols = (0..=ncols)
+        .filter(|col| !occupied_cols.contains(col))
+        .collect::<HashSet<_>>();
+    
+    galaxies
+        .iter()
+        .map(|g| Galaxy {
+            col: g.col + empty_cols.iter().filter(|&&c| g.col > c).count() * amount,
+            row: g.row + empty_rows.iter().filter(|&&r| g.row > r).count() * amount,
+        })
+        .collect()
+}
+
+/// Image of the galaxies with the galaxies and the empty rows and columns highlighted
+///
+/// The image stops at the last row and column holding a galaxy, so an image and its expansion can
+/// be drawn the same way.
+pub fn picture(title: &str, galaxies: &[Galaxy]) -> Picture {
+    let height = galaxies.iter().map(|g| g.row + 1).max().unwrap_or(0);
+    let width = galaxies.iter().map(|g| g.col + 1).max().unwrap_or(0);
+
+    let mut image = Grid::from_cells(width, vec!['.'; width * height]);
+
+    for &g in galaxies {
+        image[g] = '#';
+    }
+
+    let rows: HashSet<usize> = galaxies.iter().map(|g| g.row).collect();
+    let cols: HashSet<usize> = galaxies.iter().map(|g| g.col).collect();
+
+    let empty: Vec<Position> = image
+        .positions()
+        .filter(|p| !rows.contains(&p.row) || !cols.contains(&p.col))
+        .collect();
+
+    Picture::new(title, image)
+        .with_layer("empty rows and columns", Color::Blue, empty)
+        .with_layer("galaxies", Color::Yellow, galaxies.iter().copied())
+}
+
+/// Unordered pair of galaxies
+#[derive(Debug, Eq)]
+pub struct Pair<'a> {
+    pub g1: &'a Galaxy,
+    pub g2: &'a Galaxy,
+}
+
+impl<'a> Pair<'a> {
+    pub fn first(&self) -> &'a Galaxy {
+        self.g1.min(self.g2)
+    }
+
+    pub fn second(&self) -> &'a Galaxy {
+        self.g1.max(self.g2)
+    }
+
+    pub fn distance(&self) -> usize {
+        self.g1.manhattan(self.g2)
+    }
+}
+
+impl<'a> PartialEq for Pair<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.first() == other.first() && self.second() == other.second()
+    }
+}
+
+impl<'a> Hash for Pair<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.first().hash(state);
+        self.second().hash(state);
+    }
+}
+
+pub fn galaxy_pairs(galaxies: &[Galaxy]) -> HashSet<Pair<'_>> {
+    galaxies
+        .iter()
+        .enumerate()
+        .flat_map(|(n, g1)| galaxies
+            .iter()
+            .skip(n + 1)
+            .map(|g2| Pair { g1, g2 })
+        )
+        .collect()
+}
+
+/// Sum of the distances between every pair of galaxies, without going through the pairs
+///
+/// Along each axis, the galaxy at index `i` once the coordinates are sorted is further than the
+/// `i` galaxies before it by its coordinate times `i`, minus the sum of their coordinates.
+pub fn sum_of_distances(galaxies: &[Galaxy]) -> usize {
+    let axis_sum = |mut values: Vec<usize>| {
+        values.sort_unstable();
+
+        values
+            .iter()
+            .enumerate()
+            .scan(0, |before, (i, &value)| {
+                let distances = value * i - *before;
+                *before += value;
+                Some(distances)
+            })
+            .sum::<usize>()
+    };
+
+    axis_sum(galaxies.iter().map(|g| g.row).collect()) + axis_sum(galaxies.iter().map(|g| g.col).collect())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input<'a> = Vec<Galaxy>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        read_galaxies(input)
+    }
+
+    fn part1(galaxies: &Self::Input<'_>) -> usize {
+        sum_of_distances(&expand_galaxies(galaxies, 1))
+    }
+
+    fn part2(galaxies: &Self::Input<'_>) -> usize {
+        sum_of_distances(&expand_galaxies(galaxies, 999_999))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Generate, ParseError};
+    use grid::render::Color;
+    use indoc::indoc;
+    use proptest::collection::hash_set;
+    use proptest::prelude::*;
+
+    use crate::{Day11, galaxy_pairs};
+
+    use super::{Galaxy, Pair, expand_galaxies, picture, read_galaxies, sum_of_distances};
+
+    fn initial() -> Vec<Galaxy> {
+        let input = indoc!{"
+            ...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....
+        "};
+
+        read_galaxies(input).unwrap()
+    }
+
+    fn expanded() -> Vec<Galaxy> {
+        let input = indoc!{"
+            ....#........
+            .........#...
+            #............
+            .............
+            .............
+            ........#....
+            .#...........
+            ............#
+            .............
+            .............
+            .........#...
+            #....#.......
+        "};
+
+        read_galaxies(input).unwrap()
+    }
+
+    #[test]
+    fn parsing() {
+        let expected = vec![
+            Galaxy { row: 0, col: 3 },
+            Galaxy { row: 1, col: 7 },
+            Galaxy { row: 2, col: 0 },
+            Galaxy { row: 4, col: 6 },
+            Galaxy { row: 5, col: 1 },
+            Galaxy { row: 6, col: 9 },
+            Galaxy { row: 8, col: 7 },
+            Galaxy { row: 9, col: 0 },
+            Galaxy { row: 9, col: 4 },
+        ];
+
+        assert_eq!(initial(), expected);
+        assert_eq!(
+            read_galaxies("..#.\n.*..\n"),
+            Err(ParseError::new(2, 2, "*", "expected '#' or '.'")),
+        );
+    }
+
+    #[test]
+    fn expansion() {
+        let galaxies = initial();
+
+        assert_eq!(expand_galaxies(&galaxies, 1), expanded());
+    }
+
+    #[test]
+    fn pictures() {
+        let before = picture("Galaxies", &initial());
+        let after = picture("Expanded galaxies", &expanded());
+
+        assert_eq!((before.grid().width(), before.grid().height()), (10, 10));
+        assert_eq!((after.grid().width(), after.grid().height()), (13, 12));
+        assert_eq!(before.color(Galaxy { row: 0, col: 3 }), Some(Color::Yellow));
+        assert_eq!(before.color(Galaxy { row: 3, col: 5 }), Some(Color::Blue));
+        assert_eq!(before.color(Galaxy { row: 0, col: 0 }), None);
+        assert_eq!(after.color(Galaxy { row: 4, col: 6 }), Some(Color::Blue));
+        assert_eq!(picture("Nothing", &[]).grid().height(), 0);
+    }
+
+    #[test]
+    fn pairs() {
+        let galaxies = initial();
+        let pairs = galaxy_pairs(&galaxies);
+
+        assert_eq!(pairs.len(), 36);
+    }
+
+    #[test]
+    fn distance() {
+        let galaxies = expanded();
+        let p1 = Pair { g1: &galaxies[0], g2: &galaxies[6] };
+        let p2 = Pair { g1: &galaxies[2], g2: &galaxies[5] };
+        let p3 = Pair { g1: &galaxies[7], g2: &galaxies[8] };
+        let total_distance: usize = galaxy_pairs(&galaxies)
+            .into_iter()
+            .map(|p| p.distance())
+            .sum();
+
+        assert_eq!(p1.distance(), 15);
+        assert_eq!(p2.distance(), 17);
+        assert_eq!(p3.distance(), 5);
+        assert_eq!(total_distance, 374);
+
+        let galaxies = initial();
+        let galaxies = expand_galaxies(&galaxies, 9);
+        let total_distance: usize = galaxy_pairs(&galaxies)
+            .into_iter()
+            .map(|p| p.distance())
+            .sum();
+
+        assert_eq!(total_distance, 1030);
+
+        let galaxies = initial();
+        let galaxies = expand_galaxies(&galaxies, 99);
+        let total_distance: usize = galaxy_pairs(&galaxies)
+            .into_iter()
+            .map(|p| p.distance())
+            .sum();
+
+        assert_eq!(total_distance, 8410);
+        assert_eq!(sum_of_distances(&galaxies), 8410);
+    }
+
+    proptest! {
+        #[test]
+        fn distances_match_pairs(cells in hash_set((0..40usize, 0..40usize), 0..30), amount in 0..1000usize) {
+            let galaxies: Vec<Galaxy> = cells.into_iter().map(|(row, col)| Galaxy { row, col }).collect();
+            let galaxies = expand_galaxies(&galaxies, amount);
+            let total_distance: usize = galaxy_pairs(&galaxies)
+                .into_iter()
+                .map(|p| p.distance())
+                .sum();
+
+            prop_assert_eq!(sum_of_distances(&galaxies), total_distance);
+        }
+
+        #[test]
+        fn distances_of_generated_images(seed: u64, size in 0..50usize) {
+            let galaxies = read_galaxies(&Day11::generate(seed, size)).unwrap();
+            let total_distance: usize = galaxy_pairs(&galaxies)
+                .into_iter()
+                .map(|p| p.distance())
+                .sum();
+
+            prop_assert_eq!(sum_of_distances(&galaxies), total_distance);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Random condition records
+
+use aoc::Generate;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day12;
+
+/// Unknown springs per row, each one doubles the arrangements part 1 goes through
+pub const MAX_UNKNOWN: usize = 10;
+
+/// Row of 5 to 20 springs with at least one damaged, and the sizes of its damaged groups
+fn row<R: Rng>(rng: &mut R) -> (Vec<char>, Vec<usize>) {
+    let len = rng.gen_range(5..=20);
+    let mut springs: Vec<char> = (0..len)
+        .map(|_| if rng.gen_bool(0.4) { '#' } else { '.' })
+        .collect();
+
+    let forced = rng.gen_range(0..len);
+    springs[forced] = '#';
+
+    let groups = springs
+        .split(|&c| c == '.')
+        .map(<[char]>::len)
+        .filter(|&len| len > 0)
+        .collect();
+
+    (springs, groups)
+}
+
+impl Generate for Day12 {
+    /// Records of `size` rows, where up to [`MAX_UNKNOWN`] springs of each row are unknown
+    ///
+    /// The rows are generated before hiding springs, so each record has at least one valid
+    /// arrangement.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        (0..size)
+            .map(|_| {
+                let (mut springs, groups) = row(&mut rng);
+                let n_unknown = rng.gen_range(0..=MAX_UNKNOWN.min(springs.len()));
+
+                for i in rand::seq::index::sample(&mut rng, springs.len(), n_unknown) {
+                    springs[i] = '?';
+                }
+
+                let groups: Vec<String> = groups.iter().map(usize::to_string).collect();
+
+                format!("{} {}\n", springs.into_iter().collect::<String>(), groups.join(","))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Generate, Solution};
+
+    use crate::Day12;
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day12::generate(seed, 50);
+            let records = Day12::parse(&input).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+
+            assert_eq!(input, Day12::generate(seed, 50));
+            assert_eq!(records.len(), 50);
+            assert!(records.iter().all(|record| record.n_valid_configurations() > 0), "Seed {}", seed);
+        }
+    }
+}
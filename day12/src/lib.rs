@@ -0,0 +1,281 @@
+//! Day 12: Hot Springs
+
+pub mod generate;
+
+use std::io::BufRead;
+
+use aoc::stream::{self, ReadError, Stream};
+use aoc::{ParseError, Solution, Unsolved, parallel, parse};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spring {
+    Damaged,
+    Operational,
+}
+
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub springs: Vec<Spring>,
+}
+
+impl Row {
+    fn new() -> Self {
+        Self { springs: Vec::new() }
+    }
+
+    fn push(&mut self, spring: Spring) {
+        self.springs.push(spring);
+    }
+
+    fn with_spring(mut self, spring: Spring) -> Self {
+        self.springs.push(spring);
+        self
+    }
+
+    /// Sizes of each contiguous group of damaged springs
+    pub fn group_sizes(&self) -> Vec<usize> {
+        let mut sizes = Vec::new();
+        let mut current_size: Option<usize> = None;
+
+        for spring in &self.springs {
+            match spring {
+                Spring::Damaged => {
+                    if let Some(size) = current_size.as_mut() {
+                        *size += 1;
+                    } else {
+                        current_size = Some(1);
+                    }
+                },
+                Spring::Operational => {
+                    if let Some(size) = current_size.take() {
+                        sizes.push(size);
+                    }
+                },
+            }
+        }
+
+        if let Some(size) = current_size {
+            sizes.push(size);
+        }
+
+        sizes
+    }
+}
+
+/// Row of springs, some of which may be in an unknown condition, along with the damaged groups
+/// it must match
+#[derive(Debug, Clone)]
+pub struct Record {
+    /// Condition of each spring, or `None` when it is unknown
+    pub springs: Vec<Option<Spring>>,
+    pub groups: Vec<usize>,
+}
+
+impl Record {
+    /// Every possible arrangement of the row, obtained by trying both conditions for each unknown
+    /// spring
+    pub fn configurations(&self) -> Vec<Row> {
+        let mut configurations: Vec<Row> = vec![Row::new()];
+
+        for spring in &self.springs {
+            match spring {
+                Some(spring) => {
+                    for row in &mut configurations {
+                        row.push(*spring);
+                    }
+                },
+                None => {
+                    let mut next_configurations = Vec::with_capacity(configurations.len() * 2);
+
+                    for row in configurations {
+                        next_configurations.push(row.clone().with_spring(Spring::Damaged));
+                        next_configurations.push(row.with_spring(Spring::Operational));
+                    }
+
+                    configurations = next_configurations;
+                },
+            }
+        }
+
+        configurations
+    }
+
+    /// Number of arrangements matching the groups, found by checking every one of them
+    ///
+    /// This doubles with every unknown spring, see [`Record::n_arrangements`] for a faster way.
+    pub fn n_valid_configurations(&self) -> usize {
+        self.configurations()
+            .iter()
+            .filter(|row| row.group_sizes() == self.groups)
+            .count()
+    }
+
+    /// Number of arrangements matching the groups, counted without going through them
+    ///
+    /// `counts[i][j]` is the number of ways the springs from `i` onwards can hold the groups from
+    /// `j` onwards. The first spring is either operational, or starts the next group, which must
+    /// then be followed by the end of the row or an operational spring.
+    pub fn n_arrangements(&self) -> usize {
+        let n_springs = self.springs.len();
+        let n_groups = self.groups.len();
+        let mut counts = vec![vec![0; n_groups + 1]; n_springs + 2];
+
+        counts[n_springs][n_groups] = 1;
+        counts[n_springs + 1][n_groups] = 1;
+
+        for i in (0..n_springs).rev() {
+            for j in 0..=n_groups {
+                let mut count = 0;
+
+                if self.springs[i] != Some(Spring::Damaged) {
+                    count += counts[i + 1][j];
+                }
+
+                if let Some(&size) = self.groups.get(j) {
+                    let end = i + size;
+                    let fits = size > 0
+                        && end <= n_springs
+                        && self.springs[i..end].iter().all(|&s| s != Some(Spring::Operational))
+                        && self.springs.get(end) != Some(&Some(Spring::Damaged));
+
+                    if fits {
+                        count += counts[end + 1][j + 1];
+                    }
+                }
+
+                counts[i][j] = count;
+            }
+        }
+
+        counts[0][0]
+    }
+}
+
+impl TryFrom<&str> for Record {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let line = input.trim();
+        let (symbols, groups) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(input, &line[line.len()..], "expected a space followed by group sizes"))?;
+
+        let springs = parse::chars(input, symbols, |c| match c {
+            '.' => Ok(Some(Spring::Operational)),
+            '#' => Ok(Some(Spring::Damaged)),
+            '?' => Ok(None),
+            _ => Err("unknown spring"),
+        })?;
+
+        let groups = parse::separated(input, groups.trim(), ',')?;
+
+        Ok(Self { springs, groups })
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input<'a> = Vec<Record>;
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::lines(input)
+            .map(|line| Record::try_from(line).map_err(|err| err.within(input, line)))
+            .collect()
+    }
+
+    fn part1(records: &Self::Input<'_>) -> usize {
+        parallel::sum(records, Record::n_arrangements)
+    }
+
+    fn part2(_records: &Self::Input<'_>) -> Unsolved {
+        Unsolved
+    }
+}
+
+impl Stream for Day12 {
+    fn stream<R: BufRead>(reader: R) -> Result<(usize, Unsolved), ReadError> {
+        let arrangements = stream::lines(reader, |line| Record::try_from(line))
+            .try_fold(0, |sum, record| Ok::<_, ReadError>(sum + record?.n_arrangements()))?;
+
+        Ok((arrangements, Unsolved))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Generate, ParseError, Solution};
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::{Day12, Record, Row, Spring};
+
+    fn record() -> impl Strategy<Value = Record> {
+        let spring = prop_oneof![Just(Some(Spring::Damaged)), Just(Some(Spring::Operational)), Just(None)];
+
+        (vec(spring, 0..14), vec(1..5usize, 0..5)).prop_map(|(springs, groups)| Record { springs, groups })
+    }
+
+    proptest! {
+        #[test]
+        fn arrangements_match_enumeration(record in record()) {
+            prop_assert_eq!(record.n_arrangements(), record.n_valid_configurations());
+        }
+
+        #[test]
+        fn arrangements_of_generated_records(seed: u64, size in 1..20usize) {
+            let input = Day12::generate(seed, size);
+
+            for record in Day12::parse(&input).unwrap() {
+                prop_assert_eq!(record.n_arrangements(), record.n_valid_configurations(), "{:?}", record);
+            }
+        }
+    }
+
+    #[test]
+    fn row_groups() {
+        use super::Spring::{Damaged as D, Operational as O};
+
+        let tests = [
+            (Row { springs: vec![D, O, D, O, D, D, D] }, vec![1, 1, 3]),
+            (Row { springs: vec![O, D, O, O, O, D, O, O, O, O, D, D, D, O] }, vec![1, 1, 3]),
+            (Row { springs: vec![O, D, O, D, D, D, O, D, O, D, D, D, D, D, D] }, vec![1, 3, 1, 6]),
+        ];
+
+        for (n, (row, expected)) in tests.into_iter().enumerate() {
+            assert_eq!(row.group_sizes(), expected, "Row {} failed", n + 1)
+        }
+    }
+
+    #[test]
+    fn parse_record() {
+        let tests = [
+            ("#.#.### 1,1,3", 7, 1, vec![1, 1, 3], 1),
+            (".??..??...?##. 1,1,3", 14, 32, vec![1, 1, 3], 4),
+        ];
+
+        for (input, n_springs, n_configurations, groups, n_valid) in tests {
+            let record = Record::try_from(input).unwrap();
+
+            assert!(record.configurations().iter().all(|row| row.springs.len() == n_springs));
+            assert_eq!(record.configurations().len(), n_configurations);
+            assert_eq!(record.groups, groups);
+            assert_eq!(record.n_valid_configurations(), n_valid);
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = "#.#.### 1,1,3\n.??..?x...?##. 1,1,3\n";
+
+        assert!(matches!(Day12::parse(input), Err(err) if err == ParseError::new(2, 7, "x", "unknown spring")));
+
+        let input = "#.#.### 1,1,3\n.??..??...?##. 1,,3\n";
+
+        assert!(matches!(Day12::parse(input), Err(err) if err == ParseError::new(2, 18, "", "expected a number")));
+    }
+}
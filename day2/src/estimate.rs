@@ -0,0 +1,362 @@
+//! Most likely contents of the bag a game was played with
+//!
+//! The draws of a game are seen as samples taken from a bag holding a known number of cubes, the
+//! cubes of a draw being put back in the bag before the next one. Within a draw, cubes are either
+//! taken one at a time and put back, or all taken at once, see [`Sampling`].
+//!
+//! The log-likelihood of a bag is a sum of one concave term per color, so adding cubes one at a
+//! time to the color whose term grows the most gives the most likely bag of every size.
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use crate::game::{Colors, Game};
+use crate::part2::min_possible;
+
+/// Drop of the log-likelihood from its maximum that bounds a 95% confidence interval, half the
+/// 95% quantile of the χ² distribution with one degree of freedom
+const INTERVAL_DROP: f64 = 1.920_729_4;
+
+/// How the cubes of a draw are taken from the bag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sampling {
+    /// Each cube is put back in the bag before the next one is taken
+    WithReplacement,
+    /// The cubes of a draw are all taken at once
+    WithoutReplacement,
+}
+
+fn ln_factorial(n: u32) -> f64 {
+    (2..=n).map(|i| f64::from(i).ln()).sum()
+}
+
+/// Logarithm of the number of ways to choose `k` items out of `n`
+fn ln_choose(n: u32, k: u32) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
+    }
+
+    (0..k).map(|i| f64::from(n - i).ln()).sum::<f64>() - ln_factorial(k)
+}
+
+impl Sampling {
+    fn ln_draw_probability(self, draw: &Colors, bag: &Colors) -> f64 {
+        match self {
+            Sampling::WithReplacement => {
+                if draw.iter().any(|(color, _)| bag.get(color) == 0) {
+                    return f64::NEG_INFINITY;
+                }
+
+                let total = f64::from(bag.len());
+
+                ln_factorial(draw.len()) + draw
+                    .iter()
+                    .map(|(color, n)| f64::from(n) * (f64::from(bag.get(color)) / total).ln() - ln_factorial(n))
+                    .sum::<f64>()
+            }
+            Sampling::WithoutReplacement => {
+                if !draw.is_subset(bag) {
+                    return f64::NEG_INFINITY;
+                }
+
+                draw.iter()
+                    .map(|(color, n)| ln_choose(bag.get(color), n))
+                    .sum::<f64>() - ln_choose(bag.len(), draw.len())
+            }
+        }
+    }
+
+    /// Natural logarithm of [`Sampling::probability`], which does not vanish for long games
+    pub fn ln_probability(self, game: &Game, bag: &Colors) -> f64 {
+        game.draws
+            .iter()
+            .map(|draw| self.ln_draw_probability(draw, bag))
+            .sum()
+    }
+
+    /// Probability of getting exactly the draws of `game` from `bag`, in this order
+    pub fn probability(self, game: &Game, bag: &Colors) -> f64 {
+        self.ln_probability(game, bag).exp()
+    }
+}
+
+/// Most likely bag for a game, along with how sure we can be of it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub bag: Colors,
+    /// Natural logarithm of the probability of the game under `bag`
+    pub ln_likelihood: f64,
+    /// 95% confidence interval on the number of cubes of each color in the bag, for the colors
+    /// drawn in the game
+    pub intervals: BTreeMap<String, RangeInclusive<u32>>,
+}
+
+/// Estimator of the contents of bags holding a given number of cubes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Estimator {
+    sampling: Sampling,
+    total: u32,
+}
+
+/// Counts of every color drawn in a game, by draw, along with the fewest cubes a bag needs
+struct Samples<'a> {
+    sampling: Sampling,
+    total: u32,
+    colors: Vec<&'a str>,
+    draws: Vec<Vec<u32>>,
+    lower: Vec<u32>,
+}
+
+impl Samples<'_> {
+    /// Part of the log-likelihood that depends on the number of cubes of color `c`
+    fn term(&self, c: usize, n: u32) -> f64 {
+        match self.sampling {
+            Sampling::WithReplacement => {
+                let drawn: u32 = self.draws.iter().map(|draw| draw[c]).sum();
+                f64::from(drawn) * f64::from(n).ln()
+            }
+            Sampling::WithoutReplacement => self.draws.iter().map(|draw| ln_choose(n, draw[c])).sum(),
+        }
+    }
+
+    /// Most likely counts of every color and their log-likelihood, up to a constant, with the
+    /// count of a color fixed if `fixed` is given, or `None` if no bag is possible
+    ///
+    /// When the fixed color is the only one drawn, the cubes left are of colors that were never
+    /// drawn, which do not change the likelihood and are not counted.
+    fn allocate(&self, fixed: Option<(usize, u32)>) -> Option<(Vec<u32>, f64)> {
+        let mut counts = self.lower.clone();
+
+        if let Some((c, n)) = fixed {
+            if n < self.lower[c] {
+                return None;
+            }
+
+            counts[c] = n;
+        }
+
+        let remaining = self.total.checked_sub(counts.iter().sum())?;
+
+        for _ in 0..remaining {
+            let gain = |c: usize| self.term(c, counts[c] + 1) - self.term(c, counts[c]);
+            let Some(best) = (0..counts.len())
+                .filter(|&c| fixed.is_none_or(|(fixed, _)| fixed != c))
+                .reduce(|best, c| if gain(c) > gain(best) { c } else { best })
+            else {
+                break;
+            };
+
+            counts[best] += 1;
+        }
+
+        let value = (0..counts.len()).map(|c| self.term(c, counts[c])).sum();
+
+        Some((counts, value))
+    }
+
+    /// Counts of color `c` whose most likely bag is within [`INTERVAL_DROP`] of `best`
+    fn interval(&self, c: usize, n: u32, best: f64) -> RangeInclusive<u32> {
+        let within = |n: u32| self.allocate(Some((c, n))).is_some_and(|(_, value)| value >= best - INTERVAL_DROP);
+
+        let mut low = n;
+        while low > 0 && within(low - 1) {
+            low -= 1;
+        }
+
+        let mut high = n;
+        while high < self.total && within(high + 1) {
+            high += 1;
+        }
+
+        low..=high
+    }
+}
+
+impl Estimator {
+    pub fn new(sampling: Sampling, total: u32) -> Self {
+        Self { sampling, total }
+    }
+
+    /// Most likely bag of `total` cubes for the game, or `None` if no such bag could give its
+    /// draws
+    ///
+    /// Colors that were never drawn have no cubes in the most likely bag, and are left out of it.
+    pub fn estimate(&self, game: &Game) -> Option<Estimate> {
+        let min = min_possible(game);
+        let colors: Vec<&str> = min.iter().map(|(color, _)| color).collect();
+
+        if colors.is_empty() {
+            return None;
+        }
+
+        let lower = match self.sampling {
+            Sampling::WithReplacement => vec![1; colors.len()],
+            Sampling::WithoutReplacement => colors.iter().map(|color| min.get(color)).collect(),
+        };
+
+        let draws = game.draws
+            .iter()
+            .map(|draw| colors.iter().map(|color| draw.get(color)).collect())
+            .collect();
+
+        let samples = Samples { sampling: self.sampling, total: self.total, colors, draws, lower };
+        let (counts, best) = samples.allocate(None)?;
+
+        let intervals = samples.colors
+            .iter()
+            .enumerate()
+            .map(|(c, color)| (color.to_string(), samples.interval(c, counts[c], best)))
+            .collect();
+
+        let bag: Colors = samples.colors.iter().copied().zip(counts).collect();
+        let ln_likelihood = self.sampling.ln_probability(game, &bag);
+
+        Some(Estimate { bag, ln_likelihood, intervals })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use crate::game::{Colors, Game, parse_game};
+
+    use super::{Estimator, INTERVAL_DROP, Sampling};
+
+    fn game(line: &str) -> Game {
+        parse_game(line, line).unwrap()
+    }
+
+    /// Every bag of `total` cubes of the given colors
+    fn bags(colors: &[&str], total: u32) -> Vec<Colors> {
+        match colors {
+            [] => vec![Colors::default()],
+            [color] => vec![Colors::single(*color, total)],
+            [color, rest @ ..] => (0..=total)
+                .flat_map(|n| bags(rest, total - n).into_iter().map(move |bag| bag + Colors::single(*color, n)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn probabilities() {
+        let bag = Colors::new(2, 0, 1);
+        let one = game("Game 1: 1 red");
+        let two = game("Game 2: 2 red");
+        let both = game("Game 3: 1 red; 2 red");
+
+        for sampling in [Sampling::WithReplacement, Sampling::WithoutReplacement] {
+            assert!((sampling.probability(&one, &bag) - 2.0 / 3.0).abs() < 1e-12);
+            assert_eq!(sampling.probability(&game("Game 4: 1 green"), &bag), 0.0);
+        }
+
+        assert!((Sampling::WithReplacement.probability(&two, &bag) - 4.0 / 9.0).abs() < 1e-12);
+        assert!((Sampling::WithoutReplacement.probability(&two, &bag) - 1.0 / 3.0).abs() < 1e-12);
+        assert!((Sampling::WithoutReplacement.probability(&both, &bag) - 2.0 / 9.0).abs() < 1e-12);
+        assert_eq!(Sampling::WithoutReplacement.probability(&game("Game 5: 3 red"), &bag), 0.0);
+    }
+
+    #[test]
+    fn most_likely_bags() {
+        let game = game("Game 1: 2 red, 1 blue; 4 red, 2 blue; 6 red, 3 blue");
+        let estimate = Estimator::new(Sampling::WithReplacement, 30).estimate(&game).unwrap();
+
+        assert_eq!(estimate.bag, Colors::new(20, 0, 10));
+        assert!(estimate.intervals["red"].contains(&20));
+        assert!(estimate.intervals["red"].end() < &30);
+        assert!(estimate.intervals["blue"].start() > &0);
+
+        let estimate = Estimator::new(Sampling::WithoutReplacement, 6)
+            .estimate(&self::game("Game 2: 5 red; 1 red, 1 blue"))
+            .unwrap();
+
+        assert_eq!(estimate.bag, Colors::new(5, 0, 1));
+        assert_eq!(estimate.intervals["red"], 5..=5);
+
+        assert_eq!(Estimator::new(Sampling::WithoutReplacement, 5).estimate(&self::game("Game 3: 5 red; 1 blue")), None);
+        assert_eq!(Estimator::new(Sampling::WithReplacement, 1).estimate(&self::game("Game 4: 1 red; 1 blue")), None);
+    }
+
+    #[test]
+    fn single_color() {
+        for sampling in [Sampling::WithReplacement, Sampling::WithoutReplacement] {
+            let estimate = Estimator::new(sampling, 10).estimate(&game("Game 1: 1 red")).unwrap();
+
+            // A bag of 2 red cubes out of 10 is 1.61 below the best one, and 1 red cube 2.30 below
+            assert_eq!(estimate.bag, Colors::new(10, 0, 0));
+            assert_eq!(estimate.intervals["red"], 2..=10);
+        }
+    }
+
+    #[test]
+    fn narrower_intervals_with_more_draws() {
+        let estimator = Estimator::new(Sampling::WithoutReplacement, 40);
+        let few = estimator.estimate(&game("Game 1: 3 red, 1 blue")).unwrap();
+        let many = estimator.estimate(&game("Game 2: 3 red, 1 blue; 3 red, 1 blue; 3 red, 1 blue; 3 red, 1 blue")).unwrap();
+
+        let width = |range: &std::ops::RangeInclusive<u32>| range.end() - range.start();
+
+        assert_eq!(few.bag, many.bag);
+        assert!(width(&many.intervals["red"]) < width(&few.intervals["red"]));
+    }
+
+    fn draws() -> impl Strategy<Value = Game> {
+        let draw = (0..4u32, 0..4u32, 0..3u32).prop_map(|(r, g, b)| Colors::new(r, g, b));
+
+        vec(draw, 1..4).prop_map(|draws| Game { id: 1, draws })
+    }
+
+    proptest! {
+        #[test]
+        fn estimates_match_every_bag(game in draws(), total in 0..16u32, with_replacement: bool) {
+            let sampling = if with_replacement { Sampling::WithReplacement } else { Sampling::WithoutReplacement };
+            let colors = ["blue", "green", "red"];
+            let best = bags(&colors, total)
+                .iter()
+                .map(|bag| sampling.ln_probability(&game, bag))
+                .fold(f64::NEG_INFINITY, f64::max);
+
+            match Estimator::new(sampling, total).estimate(&game) {
+                Some(estimate) => {
+                    prop_assert!((estimate.ln_likelihood - best).abs() < 1e-9, "{} != {}", estimate.ln_likelihood, best);
+
+                    for (color, n) in estimate.bag.iter() {
+                        prop_assert!(estimate.intervals[color].contains(&n));
+                    }
+                }
+                None => prop_assert!(best == f64::NEG_INFINITY || game.draws.iter().all(Colors::is_empty)),
+            }
+        }
+
+        #[test]
+        fn intervals_match_every_bag(game in draws(), total in 1..16u32, with_replacement: bool) {
+            let sampling = if with_replacement { Sampling::WithReplacement } else { Sampling::WithoutReplacement };
+            let bags = bags(&["blue", "green", "red"], total);
+            let ln_probabilities: Vec<f64> = bags.iter().map(|bag| sampling.ln_probability(&game, bag)).collect();
+            let best = ln_probabilities.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+            let Some(estimate) = Estimator::new(sampling, total).estimate(&game) else {
+                return Ok(());
+            };
+
+            for (color, interval) in &estimate.intervals {
+                for n in 0..=total {
+                    let profile = bags
+                        .iter()
+                        .zip(&ln_probabilities)
+                        .filter(|(bag, _)| bag.get(color) == n)
+                        .map(|(_, &p)| p)
+                        .fold(f64::NEG_INFINITY, f64::max);
+
+                    // Too close to the cutoff to tell apart from rounding errors
+                    if (profile - (best - INTERVAL_DROP)).abs() < 1e-9 {
+                        continue;
+                    }
+
+                    prop_assert_eq!(interval.contains(&n), profile > best - INTERVAL_DROP, "{} {}", color, n);
+                }
+            }
+        }
+    }
+}
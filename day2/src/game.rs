@@ -1,47 +1,137 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
 use std::ops::{Add, AddAssign};
 
-use nom::IResult;
-use nom::branch::alt;
-use nom::bytes::complete::tag;
-use nom::combinator::map;
-use nom::character::complete::space1;
-use nom::multi::many0;
-use nom::sequence::{preceded, pair, delimited};
+use aoc::{ParseError, parse};
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Colors of the cubes of the puzzle
+pub const PUZZLE_COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// Multiset of cubes, holding a number of cubes for each color by name
+///
+/// Colors without cubes are not part of the set, so a set with no red cube is the same with or
+/// without a count of zero for red.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "BTreeMap<String, u32>", into = "BTreeMap<String, u32>"))]
 pub struct Colors {
-    pub n_red: u32,
-    pub n_green: u32,
-    pub n_blue: u32,
+    counts: BTreeMap<String, u32>,
 }
 
 impl Colors {
+    /// Cubes of the three colors of the puzzle
     pub fn new(n_red: u32, n_green: u32, n_blue: u32) -> Self {
-        Self { n_red, n_green, n_blue }
+        PUZZLE_COLORS.into_iter().zip([n_red, n_green, n_blue]).collect()
+    }
+
+    /// `n` cubes of a single color
+    pub fn single(color: impl Into<String>, n: u32) -> Self {
+        let mut colors = Self::default();
+        colors.insert(color, n);
+        colors
+    }
+
+    /// Number of cubes of a color, zero for a color that is not in the set
+    pub fn get(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    /// Add `n` cubes of a color to the set
+    pub fn insert(&mut self, color: impl Into<String>, n: u32) {
+        if n > 0 {
+            *self.counts.entry(color.into()).or_insert(0) += n;
+        }
+    }
+
+    /// Every color of the set with its number of cubes, in the alphabetical order of the colors
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts.iter().map(|(color, &n)| (color.as_str(), n))
     }
 
-    pub fn red(n: u32) -> Self {
-        Self { n_red: n, n_green: 0, n_blue: 0 }
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
     }
 
-    pub fn green(n: u32) -> Self {
-        Self { n_red: 0, n_green: n, n_blue: 0 }
+    /// Total number of cubes
+    pub fn len(&self) -> u32 {
+        self.counts.values().sum()
     }
 
-    pub fn blue(n: u32) -> Self {
-        Self { n_red: 0, n_green: 0, n_blue: n }
+    /// Whether there are at least as many cubes of every color in `other`
+    pub fn is_subset(&self, other: &Colors) -> bool {
+        self.iter().all(|(color, n)| n <= other.get(color))
     }
 
+    /// Largest number of cubes of every color of either set
+    pub fn union(&self, other: &Colors) -> Colors {
+        let mut union = self.clone();
+
+        for (color, n) in other.iter() {
+            let count = union.counts.entry(color.to_string()).or_insert(0);
+            *count = (*count).max(n);
+        }
+
+        union
+    }
+
+    /// Product of the numbers of red, green and blue cubes, zero if one of them is missing
+    ///
+    /// Cubes of other colors do not count, see [`Colors::power_of`] for those.
     pub fn power(&self) -> u32 {
-        self.n_red * self.n_green * self.n_blue
+        self.power_of(PUZZLE_COLORS)
+    }
+
+    /// Product of the numbers of cubes of each of `colors`, zero if one of them is missing
+    pub fn power_of<'a>(&self, colors: impl IntoIterator<Item = &'a str>) -> u32 {
+        colors.into_iter().map(|color| self.get(color)).product()
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for Colors {
+    fn from_iter<I: IntoIterator<Item = (S, u32)>>(iter: I) -> Self {
+        let mut colors = Self::default();
+
+        for (color, n) in iter {
+            colors.insert(color, n);
+        }
+
+        colors
+    }
+}
+
+impl From<BTreeMap<String, u32>> for Colors {
+    fn from(counts: BTreeMap<String, u32>) -> Self {
+        counts.into_iter().collect()
+    }
+}
+
+impl From<Colors> for BTreeMap<String, u32> {
+    fn from(colors: Colors) -> Self {
+        colors.counts
+    }
+}
+
+/// Cubes of every color as they are written in a draw, such as `3 blue, 4 red`, with the colors
+/// in alphabetical order
+impl Display for Colors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (n, (color, count)) in self.iter().enumerate() {
+            if n > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{} {}", count, color)?;
+        }
+
+        Ok(())
     }
 }
 
 impl AddAssign for Colors {
     fn add_assign(&mut self, rhs: Colors) {
-        self.n_red += rhs.n_red;
-        self.n_green += rhs.n_green;
-        self.n_blue += rhs.n_blue;
+        for (color, n) in rhs.counts {
+            self.insert(color, n);
+        }
     }
 }
 
@@ -55,6 +145,7 @@ impl Add for Colors {
 }
 
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Game {
     pub id: u32,
     pub draws: Vec<Colors>,
@@ -72,73 +163,189 @@ impl Game {
         Self { id, draws }
     }
 
+    /// Whether every draw could have been taken from a bag holding `amounts`
     pub fn is_possible(&self, amounts: &Colors) -> bool {
-        self.draws.iter().all(|draw| {
-            draw.n_red <= amounts.n_red && draw.n_green <= amounts.n_green && draw.n_blue <= amounts.n_blue
-        })
+        self.draws.iter().all(|draw| draw.is_subset(amounts))
     }
 }
 
-fn parse_color(input: &str) -> IResult<&str, Colors> {
-    let (input, value) = nom::character::complete::u32(input)?;
-    let (input, _) = space1(input)?;
+/// Line of the record for the game, such as `Game 1: 3 blue, 4 red; 2 green`
+///
+/// Parsing the line gives back the same game. A draw without cubes, such as `0 red`, is written
+/// as a blank draw: `Game 1: ; 2 blue`.
+impl Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
 
-    let color_tags = (
-        map(tag("red"), move |_| Colors::red(value)),
-        map(tag("green"), move |_| Colors::green(value)),
-        map(tag("blue"), move |_| Colors::blue(value)),
-    );
+        for (n, draw) in self.draws.iter().enumerate() {
+            if n > 0 {
+                write!(f, "; ")?;
+            }
 
-    alt(color_tags)(input)
+            write!(f, "{}", draw)?;
+        }
+
+        Ok(())
+    }
 }
 
-fn parse_draw(input: &str) -> IResult<&str, Colors> {
-    let separator = pair(tag(","), space1);
-    let (input, mut draw) = parse_color(input)?;
-    let (rest, draws) = many0(preceded(separator, parse_draw))(input)?;
+/// Cubes of one color, such as `3 blue`, where the color is any word made of letters
+fn parse_color(source: &str, text: &str) -> Result<Colors, ParseError> {
+    let text = text.trim();
+    let (value, color) = text
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| ParseError::near(source, text, "expected a cube count followed by a color"))?;
+
+    let value = parse::number(source, value)?;
+    let color = color.trim_start();
 
-    for d in draws {
-        draw += d;
+    if !color.chars().all(char::is_alphabetic) {
+        return Err(ParseError::at(source, color, "expected a color"));
     }
 
-    Ok((rest, draw))
+    Ok(Colors::single(color, value))
 }
 
-pub fn parse_game(input: &str) -> IResult<&str, Game> {
-    let input = input.trim();
-    let (input, id) = delimited(tag("Game"), preceded(space1, nom::character::complete::u32), tag(":"))(input)?;
-    let (input, draw) = preceded(space1, parse_draw)(input)?;
-    let (rest, draws) = many0(preceded(tag(";"), preceded(space1, parse_draw)))(input)?;
-    let game = Game::new(id, draw, draws);
+/// Cubes of every color drawn at once, separated by commas, none for a blank draw
+fn parse_draw(source: &str, text: &str) -> Result<Colors, ParseError> {
+    if text.trim().is_empty() {
+        return Ok(Colors::default());
+    }
 
-    Ok((rest, game))
+    text
+        .split(',')
+        .try_fold(Colors::default(), |draw, color| Ok(draw + parse_color(source, color)?))
 }
 
-pub fn parse_games(input: &str) -> Vec<Game> {
-    input
-        .lines()
-        .map(|line| parse_game(line.trim()).expect("Could not parse line").1)
+/// Parse a line of the record, `source` is the full input the line was taken from
+pub fn parse_game(source: &str, line: &str) -> Result<Game, ParseError> {
+    let (id, draws) = parse::numbered(source, line, "Game")?;
+    let draws = draws
+        .split(';')
+        .map(|draw| parse_draw(source, draw))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { id, draws })
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse::lines(input)
+        .map(|line| parse_game(input, line))
         .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Colors, Game, parse_game};
+    use aoc::ParseError;
+    use proptest::collection::{btree_map, vec};
+    use proptest::prelude::*;
+
+    use super::{Colors, Game, parse_game, parse_games};
+
+    fn game() -> impl Strategy<Value = Game> {
+        let draw = btree_map("[a-z]{1,8}", 0..100u32, 1..5).prop_map(Colors::from);
+
+        (0..1000u32, vec(draw, 1..6)).prop_map(|(id, draws)| Game { id, draws })
+    }
 
     #[test]
     fn test_parse_game() -> Result<(), Box<dyn std::error::Error>> {
         let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
-        let (_, parsed) = parse_game(line)?;
+        let parsed = parse_game(line, line)?;
         let expected = Game {
             id: 1,
             draws: vec![
-                Colors { n_red: 4, n_green: 0, n_blue: 3 },
-                Colors { n_red: 1, n_green: 2, n_blue: 6 },
-                Colors { n_red: 0, n_green: 2, n_blue: 0 },
+                Colors::new(4, 0, 3),
+                Colors::new(1, 2, 6),
+                Colors::new(0, 2, 0),
             ],
         };
 
         assert_eq!(parsed, expected);
         Ok(())
     }
+
+    #[test]
+    fn test_other_colors() {
+        let line = "Game 7: 3 purple, 1 red; 2 teal, 2 purple, 1 teal";
+        let game = parse_game(line, line).unwrap();
+        let bag: Colors = [("purple", 3), ("red", 1), ("teal", 3)].into_iter().collect();
+
+        assert_eq!(game.draws[1], Colors::single("teal", 3) + Colors::single("purple", 2));
+        assert_eq!(game.draws[1].len(), 5);
+        assert!(game.is_possible(&bag));
+        assert!(!game.is_possible(&Colors::new(12, 13, 14)));
+        assert_eq!(game.draws[0].union(&game.draws[1]), bag);
+        assert_eq!(bag.power(), 0);
+        assert_eq!(bag.power_of(["purple", "red", "teal"]), 9);
+        assert_eq!(bag.power_of(["purple", "teal", "gold"]), 0);
+        assert_eq!(bag.iter().map(|(color, _)| color).collect::<Vec<_>>(), ["purple", "red", "teal"]);
+        assert_eq!(Colors::new(0, 2, 0), Colors::single("green", 2));
+        assert_eq!(Colors::default().power(), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        let line = "Game 12: 4 red, 3 blue; 2 red, 1 red;  5 teal";
+        let game = parse_game(line, line).unwrap();
+
+        assert_eq!(game.to_string(), "Game 12: 3 blue, 4 red; 3 red; 5 teal");
+        assert_eq!(parse_game(line, &game.to_string()), Ok(game));
+        assert_eq!(Colors::new(1, 2, 3).to_string(), "3 blue, 2 green, 1 red");
+        assert_eq!(Colors::default().to_string(), "");
+
+        let line = "Game 1: 0 red; 2 blue";
+        let game = parse_game(line, line).unwrap();
+
+        assert_eq!(game.to_string(), "Game 1: ; 2 blue");
+        assert_eq!(parse_game(line, &game.to_string()), Ok(game));
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trip(game in game()) {
+            let line = game.to_string();
+
+            prop_assert_eq!(parse_game(&line, &line), Ok(game));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let line = "Game 3: 3 blue, 4 red; 2 green";
+        let game = parse_game(line, line).unwrap();
+
+        let json = serde_json::to_string(&game).unwrap();
+
+        assert_eq!(json, r#"{"id":3,"draws":[{"blue":3,"red":4},{"green":2}]}"#);
+        assert_eq!(serde_json::from_str::<Game>(&json).unwrap(), game);
+
+        let toml = toml::to_string(&game).unwrap();
+
+        assert_eq!(toml::from_str::<Game>(&toml).unwrap(), game);
+        assert_eq!(serde_json::from_str::<Colors>(r#"{"red":0,"blue":2}"#).unwrap(), Colors::new(0, 0, 2));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 purple4\n";
+
+        assert_eq!(
+            parse_games(input),
+            Err(ParseError::new(2, 11, "purple4", "expected a color")),
+        );
+        assert_eq!(
+            parse_games("Game 1: 3 blue, red\n"),
+            Err(ParseError::new(1, 17, "red", "expected a cube count followed by a color")),
+        );
+        assert_eq!(
+            parse_games("Game 1: 3 blue, , 2 red\n"),
+            Err(ParseError::new(1, 16, "", "expected a cube count followed by a color")),
+        );
+        assert_eq!(
+            parse_games("Game 1: 3 blue; x green\n"),
+            Err(ParseError::new(1, 17, "x", "expected a number")),
+        );
+    }
 }
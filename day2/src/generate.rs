@@ -0,0 +1,53 @@
+//! Random records of games
+
+use aoc::Generate;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day2;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// Cubes of one to three colours, in any order
+fn draw<R: Rng>(rng: &mut R) -> String {
+    let n_colors = rng.gen_range(1..=COLORS.len());
+
+    COLORS
+        .choose_multiple(rng, n_colors)
+        .map(|color| format!("{} {}", rng.gen_range(1..=20), color))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Generate for Day2 {
+    /// Record of `size` games, each made of one to six draws
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        (1..=size)
+            .map(|id| {
+                let draws: Vec<String> = (0..rng.gen_range(1..=6)).map(|_| draw(&mut rng)).collect();
+                format!("Game {}: {}\n", id, draws.join("; "))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Generate, Solution};
+
+    use crate::Day2;
+
+    #[test]
+    fn generated() {
+        for seed in 0..20 {
+            let input = Day2::generate(seed, 100);
+
+            assert_eq!(input, Day2::generate(seed, 100));
+            assert_eq!(Day2::parse(&input).map(|games| games.len()), Ok(100));
+            aoc::solve::<Day2>(&input, None).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Day 2: Cube Conundrum
+
+pub mod estimate;
+pub mod game;
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod query;
+pub mod simulate;
+
+use std::io::BufRead;
+
+use aoc::stream::{self, ReadError, Stream};
+use aoc::{ParseError, Solution};
+
+use game::Game;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    type Input<'a> = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        game::parse_games(input)
+    }
+
+    fn part1(games: &Self::Input<'_>) -> u32 {
+        part1::sum(games)
+    }
+
+    fn part2(games: &Self::Input<'_>) -> u32 {
+        part2::power_sum(games)
+    }
+}
+
+impl Stream for Day2 {
+    fn stream<R: BufRead>(reader: R) -> Result<(u32, u32), ReadError> {
+        stream::lines(reader, |line| game::parse_game(line, line))
+            .try_fold((0, 0), |(sum, power_sum), game| {
+                let game = game?;
+                let id = if game.is_possible(&part1::COLOR_LIMITS) { game.id } else { 0 };
+
+                Ok((sum + id, power_sum + part2::min_possible(&game).power()))
+            })
+    }
+}
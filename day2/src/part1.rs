@@ -1,10 +1,8 @@
+use std::sync::LazyLock;
+
 use crate::game::{Colors, Game};
 
-static COLOR_LIMITS: Colors = Colors {
-    n_red: 12,
-    n_green: 13,
-    n_blue: 14
-};
+pub static COLOR_LIMITS: LazyLock<Colors> = LazyLock::new(|| Colors::new(12, 13, 14));
 
 pub fn sum(games: &[Game]) -> u32 {
     let mut total: u32 = 0;
@@ -32,7 +30,7 @@ mod tests {
             Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
             Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
-        let games = parse_games(test_lines);
+        let games = parse_games(test_lines).unwrap();
 
         assert_eq!(sum(&games), 8);
     }
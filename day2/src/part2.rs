@@ -1,15 +1,10 @@
 use crate::game::{Colors, Game};
 
-fn min_possible(game: &Game) -> Colors {
-    let mut colors = Colors::default();
-
-    for draw in &game.draws {
-        colors.n_red = u32::max(colors.n_red, draw.n_red);
-        colors.n_green = u32::max(colors.n_green, draw.n_green);
-        colors.n_blue = u32::max(colors.n_blue, draw.n_blue);
-    }
-
-    colors
+/// Smallest set of cubes that makes the game possible
+pub fn min_possible(game: &Game) -> Colors {
+    game.draws
+        .iter()
+        .fold(Colors::default(), |colors, draw| colors.union(draw))
 }
 
 pub fn power_sum(games: &[Game]) -> u32 {
@@ -47,6 +42,7 @@ mod tests {
         ];
 
         let actual: Vec<Colors> = parse_games(TEST_LINES)
+            .unwrap()
             .iter()
             .map(min_possible)
             .collect();
@@ -56,6 +52,26 @@ mod tests {
 
     #[test]
     fn test_power_sum() {
-        assert_eq!(power_sum(&parse_games(TEST_LINES)), 2286);
+        assert_eq!(power_sum(&parse_games(TEST_LINES).unwrap()), 2286);
+    }
+
+    #[test]
+    fn test_missing_color() {
+        let games = parse_games("Game 1: 4 red, 6 blue; 1 red\nGame 2: 2 red, 3 green, 1 blue, 5 teal").unwrap();
+
+        assert_eq!(min_possible(&games[0]).power(), 0);
+        assert_eq!(Colors::new(4, 0, 6).power(), 0);
+        assert_eq!(Colors::default().power(), 0);
+        assert_eq!(power_sum(&games), 6);
+        assert_eq!(min_possible(&games[1]).power_of(["red", "teal"]), 10);
+    }
+
+    #[test]
+    fn test_custom_colors() {
+        let games = parse_games("Game 1: 2 teal, 1 gold; 3 teal\nGame 2: 1 teal; 4 gold, 1 red").unwrap();
+        let powers: Vec<u32> = games.iter().map(|game| min_possible(game).power_of(["gold", "teal"])).collect();
+
+        assert_eq!(powers, [3, 4]);
+        assert_eq!(power_sum(&games), 0);
     }
 }
@@ -0,0 +1,238 @@
+//! Questions about which bags make which games possible
+//!
+//! A game is possible for a bag exactly when the bag holds its [`min_possible`] profile, so every
+//! query is answered from the profiles of the games alone.
+
+use std::cmp::Reverse;
+
+use crate::game::{Colors, Game};
+use crate::part2::min_possible;
+
+/// Games that a larger number of cubes of a single color would make possible
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unlock {
+    pub color: String,
+    /// Smallest number of cubes to add to make every game of `games` possible
+    pub increase: u32,
+    pub games: Vec<u32>,
+}
+
+/// Smallest set of cubes of each game, by game id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profiles {
+    profiles: Vec<(u32, Colors)>,
+    /// Every color drawn in any game, in alphabetical order
+    colors: Vec<String>,
+}
+
+impl Profiles {
+    pub fn new(games: &[Game]) -> Self {
+        let profiles: Vec<(u32, Colors)> = games.iter().map(|game| (game.id, min_possible(game))).collect();
+
+        let mut colors: Vec<String> = profiles
+            .iter()
+            .flat_map(|(_, profile)| profile.iter().map(|(color, _)| color.to_string()))
+            .collect();
+
+        colors.sort_unstable();
+        colors.dedup();
+
+        Self { profiles, colors }
+    }
+
+    /// Ids of the games that are possible with `bag`
+    pub fn possible(&self, bag: &Colors) -> Vec<u32> {
+        self.profiles
+            .iter()
+            .filter(|(_, profile)| profile.is_subset(bag))
+            .map(|&(id, _)| id)
+            .collect()
+    }
+
+    /// Bag with the fewest cubes that makes at least `k` games possible, or `None` if there are
+    /// fewer than `k` games
+    ///
+    /// The number of cubes of each color of such a bag is the number in the profile of one of the
+    /// games, so every combination of those numbers is tried for all colors but the last. Given
+    /// the others, the last color needs as many cubes as the `k`-th smallest number among the
+    /// games they allow.
+    pub fn smallest_bag(&self, k: usize) -> Option<Colors> {
+        if k > self.profiles.len() {
+            return None;
+        }
+
+        if k == 0 || self.colors.is_empty() {
+            return Some(Colors::default());
+        }
+
+        let candidates: Vec<&Colors> = self.profiles.iter().map(|(_, profile)| profile).collect();
+        let mut best = None;
+
+        self.search(k, &candidates, &mut Vec::new(), &mut best);
+
+        best.map(|(_, counts): (u32, Vec<u32>)| self.colors.iter().map(String::as_str).zip(counts).collect())
+    }
+
+    /// Smallest bags with the counts of the colors before the one at `counts.len()` fixed, among
+    /// the profiles of `candidates` that fit them
+    fn search(&self, k: usize, candidates: &[&Colors], counts: &mut Vec<u32>, best: &mut Option<(u32, Vec<u32>)>) {
+        let total: u32 = counts.iter().sum();
+        let color = self.colors[counts.len()].as_str();
+
+        if candidates.len() < k || best.as_ref().is_some_and(|(best, _)| total >= *best) {
+            return;
+        }
+
+        let mut values: Vec<u32> = candidates.iter().map(|profile| profile.get(color)).collect();
+        values.sort_unstable();
+
+        if counts.len() + 1 == self.colors.len() {
+            let last = values[k - 1];
+
+            if best.as_ref().is_none_or(|(best, _)| total + last < *best) {
+                counts.push(last);
+                *best = Some((total + last, counts.clone()));
+                counts.pop();
+            }
+
+            return;
+        }
+
+        values.dedup();
+
+        for value in values {
+            let fitting: Vec<&Colors> = candidates
+                .iter()
+                .copied()
+                .filter(|profile| profile.get(color) <= value)
+                .collect();
+
+            counts.push(value);
+            self.search(k, &fitting, counts, best);
+            counts.pop();
+        }
+    }
+
+    /// Color that unlocks the most games impossible with `bag` when it gets at most `budget` more
+    /// cubes, the smallest increase winning ties, or `None` if no color unlocks any game
+    pub fn best_increase(&self, bag: &Colors, budget: u32) -> Option<Unlock> {
+        self.colors
+            .iter()
+            .map(|color| {
+                let mut increase = 0;
+                let mut games = Vec::new();
+
+                for (id, profile) in &self.profiles {
+                    let missing = profile.get(color).saturating_sub(bag.get(color));
+                    let others_fit = profile
+                        .iter()
+                        .all(|(other, n)| other == color || n <= bag.get(other));
+
+                    if missing > 0 && missing <= budget && others_fit {
+                        increase = increase.max(missing);
+                        games.push(*id);
+                    }
+                }
+
+                Unlock { color: color.clone(), increase, games }
+            })
+            .filter(|unlock| !unlock.games.is_empty())
+            .reduce(|best, unlock| {
+                let better = (unlock.games.len(), Reverse(unlock.increase))
+                    > (best.games.len(), Reverse(best.increase));
+
+                if better { unlock } else { best }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use crate::game::{Colors, Game, parse_games};
+    use crate::part2::min_possible;
+
+    use super::{Profiles, Unlock};
+
+    static TEST_LINES: &str =
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    fn profiles() -> Profiles {
+        Profiles::new(&parse_games(TEST_LINES).unwrap())
+    }
+
+    #[test]
+    fn possible_games() {
+        assert_eq!(profiles().possible(&Colors::new(12, 13, 14)), [1, 2, 5]);
+        assert_eq!(profiles().possible(&Colors::default()), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn smallest_bags() {
+        let profiles = profiles();
+
+        assert_eq!(profiles.smallest_bag(0), Some(Colors::default()));
+        assert_eq!(profiles.smallest_bag(1), Some(Colors::new(1, 3, 4)));
+        assert_eq!(profiles.smallest_bag(2), Some(Colors::new(6, 3, 4)));
+        assert_eq!(profiles.smallest_bag(5), Some(Colors::new(20, 13, 15)));
+        assert_eq!(profiles.smallest_bag(6), None);
+    }
+
+    #[test]
+    fn increases() {
+        let profiles = profiles();
+        let bag = Colors::new(12, 13, 14);
+
+        assert_eq!(
+            profiles.best_increase(&bag, 100),
+            Some(Unlock { color: "red".to_string(), increase: 8, games: vec![3] }),
+        );
+        assert_eq!(profiles.best_increase(&bag, 5), None);
+        assert_eq!(
+            profiles.best_increase(&Colors::new(6, 3, 4), 2),
+            Some(Unlock { color: "blue".to_string(), increase: 2, games: vec![1] }),
+        );
+    }
+
+    fn games() -> impl Strategy<Value = Vec<Game>> {
+        let draw = (0..6u32, 0..6u32, 0..6u32).prop_map(|(r, g, b)| Colors::new(r, g, b));
+
+        vec(vec(draw, 1..3), 0..8).prop_map(|games| {
+            games
+                .into_iter()
+                .zip(1..)
+                .map(|(draws, id)| Game { id, draws })
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn smallest_bag_matches_every_subset(games in games(), k in 0..9usize) {
+            let profiles: Vec<Colors> = games.iter().map(min_possible).collect();
+            let smallest = (0u32..1 << games.len())
+                .filter(|subset| subset.count_ones() as usize >= k)
+                .map(|subset| {
+                    (0..games.len())
+                        .filter(|&n| subset & (1 << n) != 0)
+                        .fold(Colors::default(), |bag, n| bag.union(&profiles[n]))
+                        .len()
+                })
+                .min();
+
+            let bag = Profiles::new(&games).smallest_bag(k);
+
+            prop_assert_eq!(bag.as_ref().map(Colors::len), smallest);
+
+            if let Some(bag) = bag {
+                prop_assert!(Profiles::new(&games).possible(&bag).len() >= k);
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Games played with a known bag, to check what is inferred from games against the truth
+//!
+//! Each draw takes a handful of cubes from the bag at once, and the cubes are put back in the bag
+//! before the next draw, as in the puzzle. The same seed always gives the same games.
+
+use std::ops::RangeInclusive;
+
+use rand::seq::index;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::game::{Colors, Game};
+
+/// Player drawing cubes from a bag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simulator {
+    bag: Colors,
+    draws: RangeInclusive<usize>,
+    draw_size: RangeInclusive<u32>,
+}
+
+impl Simulator {
+    /// Player drawing one to six times per game, taking from one cube to every cube of the bag
+    ///
+    /// # Panics
+    ///
+    /// If the bag is empty.
+    pub fn new(bag: Colors) -> Self {
+        assert!(!bag.is_empty(), "Cubes cannot be drawn from an empty bag");
+
+        let total = bag.len();
+
+        Self { bag, draws: 1..=6, draw_size: 1..=total }
+    }
+
+    /// Play a number of draws per game taken uniformly from `draws`
+    ///
+    /// # Panics
+    ///
+    /// If the range is empty or allows games without draws.
+    pub fn with_draws(mut self, draws: RangeInclusive<usize>) -> Self {
+        assert!(!draws.is_empty() && *draws.start() > 0, "Games need at least one draw");
+
+        self.draws = draws;
+        self
+    }
+
+    /// Take a number of cubes per draw taken uniformly from `size`, at most the whole bag
+    ///
+    /// # Panics
+    ///
+    /// If the range is empty or allows draws without cubes.
+    pub fn with_draw_size(mut self, size: RangeInclusive<u32>) -> Self {
+        assert!(!size.is_empty() && *size.start() > 0, "Draws need at least one cube");
+
+        self.draw_size = size;
+        self
+    }
+
+    pub fn bag(&self) -> &Colors {
+        &self.bag
+    }
+
+    /// Cubes taken at once from the bag
+    pub fn draw<R: Rng>(&self, rng: &mut R) -> Colors {
+        let total = self.bag.len();
+        let size = rng.gen_range(self.draw_size.clone()).min(total);
+        let mut picked = index::sample(rng, total as usize, size as usize).into_vec();
+
+        picked.sort_unstable();
+
+        let mut draw = Colors::default();
+        let mut picked = picked.into_iter().peekable();
+        let mut end = 0;
+
+        for (color, n) in self.bag.iter() {
+            end += n as usize;
+
+            let mut count = 0;
+            while picked.next_if(|&cube| cube < end).is_some() {
+                count += 1;
+            }
+
+            draw.insert(color, count);
+        }
+
+        draw
+    }
+
+    pub fn game<R: Rng>(&self, id: u32, rng: &mut R) -> Game {
+        let n_draws = rng.gen_range(self.draws.clone());
+        let draws = (0..n_draws).map(|_| self.draw(rng)).collect();
+
+        Game { id, draws }
+    }
+
+    /// Games numbered from 1 to `n`
+    pub fn games(&self, seed: u64, n: u32) -> Vec<Game> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        (1..=n).map(|id| self.game(id, &mut rng)).collect()
+    }
+
+    /// Record of the games numbered from 1 to `n`, in the syntax of the puzzle input
+    pub fn record(&self, seed: u64, n: u32) -> String {
+        self.games(seed, n)
+            .iter()
+            .map(|game| format!("{}\n", game))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::estimate::{Estimator, Sampling};
+    use crate::game::{Colors, parse_games};
+
+    use super::Simulator;
+
+    #[test]
+    fn round_trip() {
+        let bag: Colors = [("red", 12), ("green", 13), ("blue", 14), ("teal", 2)].into_iter().collect();
+        let simulator = Simulator::new(bag.clone()).with_draw_size(1..=20);
+
+        for seed in 0..20 {
+            let games = simulator.games(seed, 50);
+            let record = simulator.record(seed, 50);
+
+            assert_eq!(record, simulator.record(seed, 50));
+            assert_eq!(parse_games(&record).as_ref(), Ok(&games), "Seed {}", seed);
+
+            for game in &games {
+                assert!((1..=6).contains(&game.draws.len()));
+                assert!(game.is_possible(&bag));
+                assert!(game.draws.iter().all(|draw| (1..=20).contains(&draw.len())));
+            }
+        }
+
+        assert_ne!(simulator.record(0, 50), simulator.record(1, 50));
+        assert_eq!(Simulator::new(Colors::new(1, 0, 2)).with_draw_size(5..=5).games(0, 1)[0].draws[0], Colors::new(1, 0, 2));
+    }
+
+    #[test]
+    fn estimates_cover_the_bag() {
+        let bag = Colors::new(12, 13, 14);
+        let simulator = Simulator::new(bag.clone()).with_draws(20..=20).with_draw_size(3..=6);
+        let estimator = Estimator::new(Sampling::WithoutReplacement, bag.len());
+        let n_seeds = 30;
+
+        let covered = (0..n_seeds)
+            .filter(|&seed| {
+                let estimate = estimator.estimate(&simulator.games(seed, 1)[0]).unwrap();
+                bag.iter().all(|(color, n)| estimate.intervals[color].contains(&n))
+            })
+            .count();
+
+        // Each of the three intervals misses the bag 5% of the time
+        assert!(covered >= n_seeds as usize * 7 / 10, "Bag covered for {} seeds out of {}", covered, n_seeds);
+    }
+}
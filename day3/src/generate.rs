@@ -0,0 +1,60 @@
+//! Random engine schematics
+
+use aoc::Generate;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day3;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '&', '-'];
+
+impl Generate for Day3 {
+    /// Schematic of `size` by `size` characters, scattered with numbers of up to three digits
+    ///
+    /// Numbers on the same row are always separated, so each one can be found back.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut schematic = String::with_capacity(size * (size + 1));
+
+        for _ in 0..size {
+            let mut row: Vec<char> = Vec::with_capacity(size);
+
+            while row.len() < size {
+                let len = rng.gen_range(1..=3);
+
+                if rng.gen_bool(0.2) && row.len() + len <= size && !row.last().is_some_and(char::is_ascii_digit) {
+                    let number = rng.gen_range(10u32.pow(len as u32 - 1)..10u32.pow(len as u32));
+                    row.extend(number.to_string().chars());
+                } else if rng.gen_bool(0.1) {
+                    row.push(*SYMBOLS.choose(&mut rng).expect("Symbols are not empty"));
+                } else {
+                    row.push('.');
+                }
+            }
+
+            schematic.extend(row);
+            schematic.push('\n');
+        }
+
+        schematic
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Generate;
+
+    use crate::Day3;
+
+    #[test]
+    fn generated() {
+        for seed in 0..20 {
+            let input = Day3::generate(seed, 40);
+
+            assert_eq!(input, Day3::generate(seed, 40));
+            assert!(input.lines().all(|line| line.len() == 40));
+            aoc::solve::<Day3>(&input, None).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+        }
+    }
+}
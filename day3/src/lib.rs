@@ -0,0 +1,128 @@
+//! Day 3: Gear Ratios
+
+pub mod generate;
+pub mod schematic;
+
+use aoc::{ParseError, Solution};
+
+use schematic::Schematic;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type Input<'a> = Schematic;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Schematic::try_from(input)
+    }
+
+    fn part1(schematic: &Self::Input<'_>) -> usize {
+        schematic.part_numbers().into_iter().sum()
+    }
+
+    fn part2(schematic: &Self::Input<'_>) -> usize {
+        schematic.gear_ratios()
+            .into_iter()
+            .map(|(g1, g2)| g1 * g2)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::schematic::Schematic;
+
+    static INPUT: &str = include_str!("../../inputs/day3.txt");
+
+    static EXPECTED_PART_NUMBERS: [usize; 1092] = [224, 718, 378, 284, 310, 313, 744, 486, 485, 741,
+        359, 666, 439, 925, 235, 440, 251, 262, 752, 774, 515, 705, 746, 942, 591, 347, 470, 257, 637,
+        793, 299, 813, 509, 688, 82, 901, 814, 80, 17, 679, 661, 222, 875, 213, 161, 964, 998, 310,
+        258, 85, 735, 586, 650, 23, 760, 202, 598, 536, 702, 705, 793, 957, 935, 965, 357, 242, 956,
+        118, 959, 184, 401, 527, 63, 955, 77, 978, 846, 849, 699, 790, 911, 87, 486, 914, 842, 127,
+        844, 235, 209, 710, 735, 144, 226, 298, 560, 431, 586, 723, 482, 871, 918, 329, 906, 350, 365,
+        361, 536, 793, 664, 739, 507, 289, 256, 73, 356, 834, 96, 939, 28, 553, 526, 501, 746, 890,
+        701, 807, 447, 97, 58, 891, 346, 699, 421, 666, 198, 791, 340, 433, 316, 81, 984, 462, 116,
+        711, 963, 380, 85, 224, 370, 923, 188, 326, 67, 582, 530, 489, 59, 100, 441, 161, 637, 961,
+        118, 21, 216, 432, 309, 64, 992, 81, 781, 644, 324, 126, 166, 385, 233, 436, 517, 88, 390, 355,
+        335, 372, 254, 294, 589, 803, 38, 56, 235, 196, 948, 359, 398, 608, 145, 346, 915, 485, 945,
+        936, 114, 490, 61, 343, 345, 862, 313, 316, 529, 362, 49, 307, 791, 281, 222, 283, 911, 90,
+        914, 39, 509, 797, 23, 895, 67, 164, 896, 554, 460, 181, 211, 474, 201, 911, 717, 192, 75, 679,
+        355, 678, 650, 128, 925, 230, 105, 409, 897, 698, 427, 313, 670, 308, 838, 335, 367, 325, 233,
+        224, 950, 199, 793, 108, 501, 102, 924, 349, 595, 395, 753, 318, 676, 154, 524, 908, 694, 211,
+        214, 424, 458, 403, 958, 688, 957, 488, 591, 218, 203, 196, 765, 971, 927, 741, 227, 592, 60,
+        885, 474, 409, 226, 174, 230, 162, 169, 510, 962, 32, 225, 201, 823, 914, 724, 89, 267, 717,
+        54, 60, 69, 601, 306, 287, 65, 308, 926, 278, 415, 241, 827, 265, 662, 678, 734, 424, 434, 701,
+        380, 731, 24, 973, 741, 728, 357, 881, 238, 740, 947, 587, 22, 271, 603, 266, 918, 519, 617,
+        826, 268, 486, 295, 939, 363, 378, 302, 586, 740, 380, 931, 12, 387, 588, 315, 712, 956, 180,
+        454, 104, 661, 91, 811, 370, 471, 419, 464, 243, 578, 250, 784, 750, 998, 245, 165, 771, 691,
+        768, 654, 261, 566, 441, 91, 194, 643, 932, 379, 86, 940, 263, 638, 344, 991, 544, 767, 266,
+        708, 727, 191, 698, 990, 308, 325, 44, 18, 397, 93, 649, 900, 62, 322, 339, 619, 404, 742, 196,
+        874, 205, 96, 203, 224, 704, 727, 135, 769, 757, 424, 714, 5, 884, 114, 674, 144, 83, 302, 29,
+        179, 104, 531, 455, 473, 300, 254, 640, 296, 548, 103, 381, 365, 713, 744, 709, 837, 9, 90,
+        347, 179, 359, 304, 923, 446, 385, 699, 749, 193, 206, 922, 197, 128, 273, 758, 439, 650, 54,
+        736, 817, 152, 949, 807, 224, 124, 889, 625, 667, 239, 387, 806, 788, 632, 8, 584, 280, 11,
+        726, 181, 761, 66, 64, 14, 742, 725, 708, 332, 828, 750, 425, 944, 719, 110, 348, 121, 961,
+        971, 629, 830, 241, 491, 930, 889, 710, 333, 163, 311, 252, 270, 165, 208, 694, 914, 267, 952,
+        645, 414, 822, 69, 112, 267, 7, 806, 931, 355, 928, 854, 993, 101, 897, 381, 748, 987, 437,
+        954, 595, 783, 49, 110, 675, 8, 60, 981, 730, 336, 120, 790, 90, 212, 985, 12, 912, 383, 665,
+        754, 950, 790, 896, 773, 777, 581, 514, 910, 579, 496, 790, 334, 912, 692, 609, 694, 693, 213,
+        620, 695, 385, 785, 163, 984, 978, 971, 477, 465, 196, 313, 447, 766, 126, 480, 798, 792, 359,
+        193, 837, 157, 773, 752, 447, 565, 53, 384, 449, 749, 792, 138, 527, 339, 299, 505, 691, 700,
+        721, 942, 713, 260, 491, 452, 336, 73, 960, 238, 163, 689, 831, 709, 199, 111, 498, 557, 972,
+        450, 653, 476, 398, 619, 280, 923, 31, 691, 67, 782, 268, 428, 408, 740, 576, 734, 317, 114,
+        332, 743, 908, 381, 914, 420, 647, 528, 284, 825, 721, 929, 324, 167, 561, 815, 466, 389, 304,
+        480, 851, 585, 942, 496, 53, 938, 518, 368, 941, 930, 364, 522, 59, 652, 963, 949, 828, 784,
+        860, 420, 310, 476, 94, 589, 833, 426, 940, 959, 993, 88, 398, 624, 917, 838, 664, 833, 672,
+        19, 350, 333, 707, 336, 746, 263, 463, 220, 663, 849, 631, 712, 308, 580, 78, 422, 444, 281,
+        447, 37, 814, 788, 810, 369, 985, 92, 684, 74, 279, 128, 464, 944, 978, 163, 984, 5, 165, 889,
+        969, 5, 272, 374, 948, 13, 796, 632, 789, 91, 66, 152, 508, 3, 544, 703, 774, 434, 794, 932,
+        241, 74, 974, 954, 375, 772, 218, 414, 366, 501, 566, 599, 256, 468, 101, 156, 998, 312, 767,
+        95, 838, 162, 130, 583, 544, 176, 936, 826, 421, 139, 893, 284, 724, 85, 438, 51, 616, 698, 36,
+        262, 357, 193, 596, 844, 387, 84, 362, 98, 14, 394, 56, 432, 512, 704, 74, 196, 875, 132, 229,
+        723, 568, 108, 55, 172, 217, 289, 427, 795, 336, 94, 988, 86, 838, 902, 922, 288, 719, 845,
+        558, 524, 195, 704, 842, 166, 883, 503, 451, 600, 900, 280, 169, 586, 751, 116, 877, 418, 194,
+        644, 244, 354, 118, 208, 788, 826, 207, 152, 893, 608, 948, 203, 206, 977, 916, 49, 385, 266,
+        607, 990, 964, 340, 697, 62, 526, 675, 612, 604, 492, 884, 212, 157, 679, 706, 716, 518, 103,
+        749, 989, 739, 684, 35, 785, 343, 680, 423, 476, 420, 241, 532, 864, 826, 403, 311, 60, 451,
+        562, 420, 321, 241, 240, 845, 968, 389, 177, 640, 82, 963, 211, 38, 901, 253, 37, 218, 501,
+        320, 215, 264, 298, 970, 801, 569, 3, 859, 136, 818, 212, 91, 72, 750, 775, 728, 29, 984, 639,
+        670, 959, 438, 524, 943, 755, 162, 446, 290, 751, 557, 483, 189, 9, 786, 978, 445, 350, 860,
+        662, 354, 682, 117, 57, 441, 88, 142, 927, 402, 874, 734, 926, 633, 219, 84, 662, 205, 981,
+        367, 338, 332, 119, 375, 334, 146, 511, 165, 666, 614, 744, 617, 887, 848, 203, 593, 158, 152,
+        817, 866, 904, 701, 165, 977, 93, 396, 281, 953, 116, 97, 70, 179, 107, 338, 622, 445, 382,
+        672, 859, 571, 717, 240, 175, 808, 225, 988, 604, 448, 651, 769, 569, 975, 968, 394, 498, 969,
+        360, 666, 919, 407, 933, 101, 58];
+
+    #[test]
+    fn test_part1() {
+        let schematic = Schematic::default();
+        let part_sum: usize = schematic.part_numbers().into_iter().sum();
+
+        assert_eq!(part_sum, 4361);
+    }
+
+    #[test]
+    fn test_part2() {
+        let schematic = Schematic::default();
+        let ratio_sum: usize = schematic.gear_ratios()
+            .into_iter()
+            .map(|(g1, g2)| g1 * g2)
+            .sum();
+
+        assert_eq!(ratio_sum, 467835);
+    }
+
+    #[test]
+    fn expected_part_numbers() {
+        let schematic = Schematic::try_from(INPUT).unwrap();
+        let part_numbers = schematic.part_numbers();
+
+        for num in &EXPECTED_PART_NUMBERS {
+            assert!(part_numbers.contains(num), "Missing part number {}", num)
+        }
+    }
+}
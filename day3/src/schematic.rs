@@ -1,11 +1,8 @@
 use std::collections::HashSet;
-use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Eq, Hash)]
-struct Position {
-    x: usize,
-    y: usize,
-}
+use aoc::ParseError;
+use grid::render::{Color, Picture};
+use grid::{Grid, Position};
 
 #[derive(Debug, Hash, PartialEq, Eq)]
 struct Symbol {
@@ -13,123 +10,96 @@ struct Symbol {
     pos: Position,
 }
 
+/// Number written on the schematic, `pos` is the position of its first digit
 #[derive(Debug, Hash, PartialEq, Eq)]
-struct Number<'a> {
-    value: &'a str,
-    row: usize,
-    start_col: usize,
+struct Number {
+    value: usize,
+    len: usize,
+    pos: Position,
 }
 
-impl<'a> Number<'a> {
-    fn adjacent_spaces(&self) -> HashSet<Position> {
-        let mut spaces: HashSet<Position> = HashSet::new();
-
-        if self.start_col > 0 {
-            let x = self.start_col - 1;
-
-            if self.row > 0 {
-                spaces.insert(Position { x, y: self.row - 1 });
-            }
-
-            spaces.insert(Position { x, y: self.row });
-            spaces.insert(Position { x, y: self.row + 1 });
-        }
-
-        for i in 0..self.value.len() {
-            let x = self.start_col + i; 
-
-            if self.row > 0 {
-                spaces.insert(Position { x, y: self.row - 1 });
-            }
-
-            spaces.insert(Position { x, y: self.row + 1 });
-        }
-
-        {
-            let x = self.start_col + self.value.len();
-            
-            if self.row > 0 {
-                spaces.insert(Position { x, y: self.row - 1});
-            }
+impl Number {
+    fn digits(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.len).map(|i| Position::new(self.pos.row, self.pos.col + i))
+    }
 
-            spaces.insert(Position { x, y: self.row });
-            spaces.insert(Position { x, y: self.row + 1 });
-        }
+    fn adjacent_spaces(&self) -> HashSet<Position> {
+        let digits: HashSet<Position> = self.digits().collect();
 
-        spaces
+        self.digits()
+            .flat_map(|p| p.neighbours8())
+            .filter(|p| !digits.contains(p))
+            .collect()
     }
 }
 
 #[derive(Debug)]
-pub struct Schematic<'a> {
-    numbers: HashSet<Number<'a>>,
+pub struct Schematic {
+    numbers: HashSet<Number>,
     symbols: HashSet<Symbol>,
+    grid: Grid<char>,
 }
 
-impl<'a> Schematic<'a> {
-    pub fn from_str(input: &'a str) -> Self {
+impl TryFrom<&str> for Schematic {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::try_from(input)?;
         let mut numbers = HashSet::new();
         let mut symbols = HashSet::new();
+        let mut current: Option<Number> = None;
 
-        for (nrow, line) in input.lines().enumerate() {
-            let line = line.trim();
-            let mut start: Option<usize> = None;
-
-            for (ncol, c) in line.chars().enumerate() {
-                if c.is_digit(10) {
-                    if start.is_none() {
-                        start = Some(ncol);
-                    }
-                } else {
-                    if let Some(s) = start {
-                        let substr = line.get(s..ncol).expect("Could not extract number substring");
-                        let n = Number {
-                            value: substr,
-                            row: nrow,
-                            start_col: s,
-                        };
-                        
-                        numbers.insert(n);
-                        start = None;
-                    }
-
-                    if c != '.' {
-                        let s = Symbol {
-                            value: c,
-                            pos: Position {x: ncol, y: nrow },
-                        };
-
-                        symbols.insert(s);
-                    }
-                }
+        for (pos, &c) in grid.iter() {
+            if pos.col == 0 {
+                numbers.extend(current.take());
             }
 
-            if let Some(s) = start {
-                let substr = line.get(s..).expect("Could not extract number substring");
-                let n = Number {
-                    value: substr,
-                    row: nrow,
-                    start_col: s,
-                };
+            if let Some(digit) = c.to_digit(10) {
+                let number = current.get_or_insert(Number { value: 0, len: 0, pos });
+
+                number.value = number.value * 10 + digit as usize;
+                number.len += 1;
+            } else {
+                numbers.extend(current.take());
 
-                numbers.insert(n);
+                if c != '.' {
+                    symbols.insert(Symbol { value: c, pos });
+                }
             }
         }
 
-        Self { numbers, symbols }
+        numbers.extend(current);
+
+        Ok(Self { numbers, symbols, grid })
+    }
+}
+
+impl Schematic {
+    fn is_part(&self, number: &Number) -> bool {
+        let spaces = number.adjacent_spaces();
+        self.symbols.iter().any(|sym| spaces.contains(&sym.pos))
     }
 
     pub fn part_numbers(&self) -> Vec<usize> {
         self.numbers
             .iter()
-            .filter(|num| {
-                let spaces = num.adjacent_spaces();
-                self.symbols.iter().any(|sym| spaces.contains(&sym.pos))
-            })
-            .map(|num| usize::from_str(num.value).expect("Could not parse part number"))
+            .filter(|num| self.is_part(num))
+            .map(|num| num.value)
             .collect()
     }
 
+    /// Schematic with the symbols, the part numbers and the numbers that are not parts highlighted
+    pub fn picture(&self) -> Picture {
+        let (parts, others): (Vec<&Number>, Vec<&Number>) = self.numbers
+            .iter()
+            .partition(|num| self.is_part(num));
+
+        Picture::new("Part numbers", self.grid.clone())
+            .with_layer("symbols", Color::Yellow, self.symbols.iter().map(|sym| sym.pos))
+            .with_layer("part numbers", Color::Green, parts.into_iter().flat_map(Number::digits))
+            .with_layer("other numbers", Color::Red, others.into_iter().flat_map(Number::digits))
+    }
+
     pub fn symbols(&self) -> HashSet<char> {
         self.symbols.iter().map(|sym| sym.value).collect()
     }
@@ -142,7 +112,7 @@ impl<'a> Schematic<'a> {
                 self.numbers
                     .iter()
                     .filter(|&num| num.adjacent_spaces().contains(&sym.pos))
-                    .map(|num| usize::from_str(num.value).expect("Could not parse part number"))
+                    .map(|num| num.value)
                     .collect::<Vec<usize>>()
             })
             .filter(|parts| parts.len() == 2)
@@ -151,7 +121,7 @@ impl<'a> Schematic<'a> {
     }
 }
 
-impl Default for Schematic<'static> {
+impl Default for Schematic {
     fn default() -> Self {
         let schematic_str =
             "467..114..
@@ -165,7 +135,7 @@ impl Default for Schematic<'static> {
             ...$.*....
             .664.598..";
 
-        Schematic::from_str(schematic_str)
+        Schematic::try_from(schematic_str).expect("Example schematic is valid")
     }
 }
 
@@ -173,35 +143,38 @@ impl Default for Schematic<'static> {
 mod tests {
     use std::collections::HashSet;
 
-    use super::{Number, Position, Schematic};
+    use grid::Position;
+    use grid::render::Color;
+
+    use super::{Number, Schematic};
 
     #[test]
     fn test_adjacent_spaces() {
-        let number = Number { value: "467", row: 0, start_col: 0 };
+        let number = Number { value: 467, len: 3, pos: Position::new(0, 0) };
         let expected = HashSet::from([
-            Position { x: 0, y: 1 },
-            Position { x: 1, y: 1 },
-            Position { x: 2, y: 1 },
-            Position { x: 3, y: 1 },
-            Position { x: 3, y: 0 },
+            Position::new(1, 0),
+            Position::new(1, 1),
+            Position::new(1, 2),
+            Position::new(1, 3),
+            Position::new(0, 3),
         ]);
 
         assert_eq!(number.adjacent_spaces(), expected);
 
-        let number = Number { value: "663", row: 2, start_col: 6 };
+        let number = Number { value: 663, len: 3, pos: Position::new(2, 6) };
         let expected = HashSet::from([
-            Position { x: 5, y: 1 },
-            Position { x: 5, y: 2 },
-            Position { x: 5, y: 3 },
-            Position { x: 6, y: 1 },
-            Position { x: 6, y: 3 },
-            Position { x: 7, y: 1 },
-            Position { x: 7, y: 3 },
-            Position { x: 8, y: 1 },
-            Position { x: 8, y: 3 },
-            Position { x: 9, y: 1 },
-            Position { x: 9, y: 2 },
-            Position { x: 9, y: 3 },
+            Position::new(1, 5),
+            Position::new(2, 5),
+            Position::new(3, 5),
+            Position::new(1, 6),
+            Position::new(3, 6),
+            Position::new(1, 7),
+            Position::new(3, 7),
+            Position::new(1, 8),
+            Position::new(3, 8),
+            Position::new(1, 9),
+            Position::new(2, 9),
+            Position::new(3, 9),
         ]);
 
         assert_eq!(number.adjacent_spaces(), expected);
@@ -220,6 +193,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_picture() {
+        let picture = Schematic::default().picture();
+
+        assert_eq!(picture.color(Position::new(0, 0)), Some(Color::Green));
+        assert_eq!(picture.color(Position::new(0, 5)), Some(Color::Red));
+        assert_eq!(picture.color(Position::new(1, 3)), Some(Color::Yellow));
+        assert_eq!(picture.color(Position::new(1, 0)), None);
+    }
+
     #[test]
     fn test_gear_ratios() {
         let schematic = Schematic::default();
@@ -1,16 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
-use nom::bytes::complete::tag;
-use nom::character::complete::space1;
-use nom::combinator::eof;
-use nom::multi::many1;
-use nom::sequence::{delimited, pair, preceded};
+use aoc::{ParseError, parse};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Card {
-    id: u32,
-    numbers: Vec<u32>,
-    winning_numbers: Vec<u32>,
+    pub id: u32,
+    pub numbers: Vec<u32>,
+    pub winning_numbers: Vec<u32>,
 }
 
 impl Card {
@@ -29,19 +25,40 @@ impl Card {
             n => u32::pow(2, n - 1)
         }
     }
+}
+
+impl Card {
+    /// Parse a line of the pile, `source` is the full input the line was taken from
+    fn parse(source: &str, line: &str) -> Result<Self, ParseError> {
+        let (id, numbers) = parse::numbered(source, line, "Card")?;
+        let (winning_numbers, numbers) = numbers
+            .split_once('|')
+            .ok_or_else(|| ParseError::at(source, &line[line.len()..], "expected \"|\""))?;
+
+        Ok(Card {
+            id,
+            numbers: parse::numbers(source, numbers)?,
+            winning_numbers: parse::numbers(source, winning_numbers)?,
+        })
+    }
+}
 
-    pub fn from_str<'a>(input: &'a str) -> Result<Self, nom::Err<nom::error::Error<&'a str>>> {
-        let input = input.trim();
-        let (input, id) = delimited(tag("Card"), preceded(space1, nom::character::complete::u32), tag(":"))(input)?;
-        let (input, winning_numbers) = many1(preceded(space1, nom::character::complete::u32))(input)?;
-        let (input, _) = pair(space1, tag("|"))(input)?;
-        let (input, numbers) = many1(preceded(space1, nom::character::complete::u32))(input)?;
-        let (_, _) = eof(input)?;
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
 
-        Ok(Card { id, numbers, winning_numbers })
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        Card::parse(line, line)
     }
 }
 
+/// Parse one card per line, skipping blank lines
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    parse::lines(input)
+        .map(|line| Card::parse(input, line))
+        .collect()
+}
+
+/// Number of copies of each card that are won, indexed by card id
 pub fn copies(cards: &[Card]) -> HashMap<u32, u32> {
     let mut copies: HashMap<u32, u32> = cards
         .iter()
@@ -53,8 +70,8 @@ pub fn copies(cards: &[Card]) -> HashMap<u32, u32> {
             let id_to_copy = card.id + i;
             let id_copies = copies[&card.id];
 
-            if copies.contains_key(&id_to_copy) {
-                copies.get_mut(&id_to_copy).map(|n_copies| *n_copies += id_copies);
+            if let Some(n_copies) = copies.get_mut(&id_to_copy) {
+                *n_copies += id_copies;
             }
         }
     }
@@ -62,10 +79,53 @@ pub fn copies(cards: &[Card]) -> HashMap<u32, u32> {
     copies
 }
 
+/// Running count of the copies won, for cards given one at a time in the order of their ids
+///
+/// Only the copies won of the cards still to come are remembered, which are never more than the
+/// largest number of winners of a card.
+#[derive(Debug, Default)]
+pub struct Copies {
+    /// Copies won of each of the cards following the last one
+    won: VecDeque<u32>,
+    last_id: Option<u32>,
+    total: u32,
+}
+
+impl Copies {
+    /// Count the copies of `card`, returning how many there are
+    pub fn add(&mut self, card: &Card) -> u32 {
+        if let Some(last_id) = self.last_id {
+            let skipped = card.id.saturating_sub(last_id + 1) as usize;
+            self.won.drain(..skipped.min(self.won.len()));
+        }
+
+        let n_copies = 1 + self.won.pop_front().unwrap_or(0);
+
+        for i in 0..card.n_winners() as usize {
+            match self.won.get_mut(i) {
+                Some(won) => *won += n_copies,
+                None => self.won.push_back(n_copies),
+            }
+        }
+
+        self.last_id = Some(card.id);
+        self.total += n_copies;
+
+        n_copies
+    }
+
+    /// Number of cards once every copy has been won
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
 
+    use aoc::ParseError;
+
     use super::Card;
 
     #[test]
@@ -77,7 +137,7 @@ mod tests {
             winning_numbers: vec![24, 76, 32, 40, 51, 61, 89, 6, 30, 60],
         };
 
-        assert_eq!(Ok(expected), Card::from_str(line));
+        assert_eq!(Ok(expected), Card::try_from(line));
 
         let line = "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19";
         let expected = Card {
@@ -86,7 +146,21 @@ mod tests {
             winning_numbers: vec![13, 32, 20, 16, 61],
         };
 
-        assert_eq!(Ok(expected), Card::from_str(line));
+        assert_eq!(Ok(expected), Card::try_from(line));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30\n";
+
+        assert_eq!(
+            super::parse_cards(input),
+            Err(ParseError::new(2, 12, "x2", "expected a number")),
+        );
+        assert_eq!(
+            super::parse_cards("Card 1: 41 48 83 86\n"),
+            Err(ParseError::new(1, 20, "", "expected \"|\"")),
+        );
     }
 
     #[test]
@@ -125,10 +199,7 @@ mod tests {
             Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
         "};
 
-        let cards: Vec<Card> = cards_str
-            .lines()
-            .map(|line| Card::from_str(line.trim()).expect("Could not parse card"))
-            .collect();
+        let cards = super::parse_cards(cards_str).unwrap();
 
         let card_copies = super::copies(&cards);
 
@@ -138,5 +209,16 @@ mod tests {
         assert_eq!(card_copies[&4], 8);
         assert_eq!(card_copies[&5], 14);
         assert_eq!(card_copies[&6], 1);
+
+        let mut running = super::Copies::default();
+        let counts: Vec<u32> = cards.iter().map(|card| running.add(card)).collect();
+
+        assert_eq!(counts, [1, 2, 4, 8, 14, 1]);
+        assert_eq!(running.total(), 30);
+
+        let mut running = super::Copies::default();
+        let counts: Vec<u32> = [&cards[0], &cards[2], &cards[3]].into_iter().map(|card| running.add(card)).collect();
+
+        assert_eq!(counts, [1, 2, 4]);
     }
 }
@@ -0,0 +1,73 @@
+//! Random piles of scratchcards
+
+use aoc::Generate;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day4;
+
+/// Cards win at most this many numbers, and none on most cards
+///
+/// Each card wins copies of the following ones, so winning less than one number on average keeps
+/// the number of copies from growing exponentially with the number of cards.
+const MAX_WINNERS: usize = 3;
+
+fn numbers(values: &[u32]) -> String {
+    values
+        .iter()
+        .map(|n| format!("{:>2}", n))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Generate for Day4 {
+    /// Pile of `size` cards, each with the same number of winning numbers and numbers
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let n_winning = rng.gen_range(MAX_WINNERS..=10);
+        let n_numbers = rng.gen_range(n_winning..=25);
+        let width = size.to_string().len();
+
+        (1..=size)
+            .map(|id| {
+                let mut values: Vec<u32> = (1..100).collect();
+                values.shuffle(&mut rng);
+
+                let (winning, others) = values.split_at(n_winning);
+                let n_winners = if rng.gen_bool(0.7) { 0 } else { rng.gen_range(1..=MAX_WINNERS) };
+                let n_winners = n_winners.min(size - id);
+
+                let mut have: Vec<u32> = winning[..n_winners]
+                    .iter()
+                    .chain(&others[..n_numbers - n_winners])
+                    .copied()
+                    .collect();
+
+                have.shuffle(&mut rng);
+
+                format!("Card {:>width$}: {} | {}\n", id, numbers(winning), numbers(&have), width = width)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Generate, Solution};
+
+    use crate::Day4;
+
+    #[test]
+    fn generated() {
+        for seed in 0..20 {
+            let input = Day4::generate(seed, 200);
+            let cards = Day4::parse(&input).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+
+            assert_eq!(input, Day4::generate(seed, 200));
+            assert_eq!(cards.len(), 200);
+            assert_eq!(cards.last().map(|card| card.n_winners()), Some(0));
+            aoc::solve::<Day4>(&input, None).unwrap();
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Day 4: Scratchcards
+
+pub mod card;
+pub mod generate;
+
+use std::io::BufRead;
+
+use aoc::stream::{self, ReadError, Stream};
+use aoc::{ParseError, Solution};
+
+use card::{Card, Copies, copies};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    type Input<'a> = Vec<Card>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        card::parse_cards(input)
+    }
+
+    fn part1(cards: &Self::Input<'_>) -> u32 {
+        cards
+            .iter()
+            .map(Card::points)
+            .sum()
+    }
+
+    fn part2(cards: &Self::Input<'_>) -> u32 {
+        copies(cards)
+            .into_values()
+            .sum()
+    }
+}
+
+impl Stream for Day4 {
+    fn stream<R: BufRead>(reader: R) -> Result<(u32, u32), ReadError> {
+        let mut points = 0;
+        let mut copies = Copies::default();
+
+        for card in stream::lines(reader, |line| Card::try_from(line)) {
+            let card = card?;
+
+            points += card.points();
+            copies.add(&card);
+        }
+
+        Ok((points, copies.total()))
+    }
+}
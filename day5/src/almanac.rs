@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 use std::hash::Hash;
 
+use aoc::{ParseError, parse};
+
 use super::{One, Two};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -12,72 +14,72 @@ impl From<u64> for Seed {
     }
 }
 
-impl Into<u64> for Seed {
-    fn into(self) -> u64 {
-        self.0
+impl From<Seed> for u64 {
+    fn from(value: Seed) -> Self {
+        value.0
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Soil(u64);
 
-impl Into<u64> for Soil {
-    fn into(self) -> u64 {
-        self.0
+impl From<Soil> for u64 {
+    fn from(value: Soil) -> Self {
+        value.0
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Fertilizer(u64);
 
-impl Into<u64> for Fertilizer {
-    fn into(self) -> u64 {
-        self.0
+impl From<Fertilizer> for u64 {
+    fn from(value: Fertilizer) -> Self {
+        value.0
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Water(u64);
 
-impl Into<u64> for Water {
-    fn into(self) -> u64 {
-        self.0
+impl From<Water> for u64 {
+    fn from(value: Water) -> Self {
+        value.0
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Light(u64);
 
-impl Into<u64> for Light {
-    fn into(self) -> u64 {
-        self.0
+impl From<Light> for u64 {
+    fn from(value: Light) -> Self {
+        value.0
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Temperature(u64);
 
-impl Into<u64> for Temperature {
-    fn into(self) -> u64 {
-        self.0
+impl From<Temperature> for u64 {
+    fn from(value: Temperature) -> Self {
+        value.0
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Humidity(u64);
 
-impl Into<u64> for Humidity {
-    fn into(self) -> u64 {
-        self.0
+impl From<Humidity> for u64 {
+    fn from(value: Humidity) -> Self {
+        value.0
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Location(u64);
 
-impl Into<u64> for Location {
-    fn into(self) -> u64 {
-        self.0
+impl From<Location> for u64 {
+    fn from(value: Location) -> Self {
+        value.0
     }
 }
 
@@ -96,6 +98,11 @@ impl Range {
         start <= n && n < end
     }
 
+    /// Whether some value is in the sources of both ranges
+    fn overlaps(&self, other: &Range) -> bool {
+        self.src_start < other.src_start + other.length && other.src_start < self.src_start + self.length
+    }
+
     fn convert(&self, n: u64) -> Option<u64> {
         if self.contains(n) {
             Some((n - self.src_start) + self.dest_start)
@@ -105,20 +112,26 @@ impl Range {
     }
 }
 
-impl From<&str> for Range {
-    fn from(input: &str) -> Self {
-        let values: Vec<u64> = input
-            .split(" ")
-            .map(|s| u64::from_str_radix(s, 10).expect("Could not parse range number"))
-            .collect();
+impl Range {
+    /// Parse a line of a map, `source` is the full input the line was taken from
+    fn parse(source: &str, line: &str) -> Result<Self, ParseError> {
+        let values: Vec<u64> = parse::numbers(source, line)?;
+
+        if values.len() != 3 {
+            return Err(ParseError::at(source, line, "expected 3 numbers"));
+        }
 
-        assert_eq!(values.len(), 3);
+        // Values are converted up to the end of each range, which must be a value too
+        if values[0].checked_add(values[2]).is_none() || values[1].checked_add(values[2]).is_none() {
+            let length = line.split_whitespace().nth(2).expect("The line has 3 numbers");
+            return Err(ParseError::at(source, length, "range goes past the largest value"));
+        }
 
-        Self {
+        Ok(Self {
             src_start: values[1],
             dest_start: values[0],
             length: values[2],
-        }
+        })
     }
 }
 
@@ -131,17 +144,71 @@ impl Map {
     fn convert(&self, n: u64) -> u64 {
         self.ranges.iter().find_map(|range| range.convert(n)).unwrap_or(n)
     }
-}
 
-impl From<&str> for Map {
-    fn from(input: &str) -> Self {
-        let ranges = input
-            .lines()
-            .skip(1)
-            .map(Range::from)
+    /// Convert every value of the half-open `values`, as the ranges of values they convert to
+    ///
+    /// The values are split where the ranges of the map start and end, which relies on the ranges
+    /// not overlapping, as checked by [`Map::parse`].
+    fn convert_range(&self, values: std::ops::Range<u64>) -> Vec<std::ops::Range<u64>> {
+        let mut overlaps: Vec<(u64, u64, &Range)> = self
+            .ranges
+            .iter()
+            .map(|range| {
+                let start = values.start.max(range.src_start);
+                let end = values.end.min(range.src_start + range.length);
+                (start, end, range)
+            })
+            .filter(|(start, end, _)| start < end)
             .collect();
 
-        Self { ranges }
+        overlaps.sort_unstable_by_key(|&(start, ..)| start);
+
+        let mut converted = Vec::new();
+        let mut next = values.start;
+
+        for (start, end, range) in overlaps {
+            if next < start {
+                converted.push(next..start);
+            }
+
+            let offset = range.dest_start;
+            converted.push(start - range.src_start + offset..end - range.src_start + offset);
+            next = end;
+        }
+
+        if next < values.end {
+            converted.push(next..values.end);
+        }
+
+        converted
+    }
+}
+
+impl Map {
+    /// Parse a block made of a header line followed by one range per line
+    ///
+    /// The sources of the ranges must not overlap, so that each value converts in a single way.
+    fn parse(source: &str, block: &str) -> Result<Self, ParseError> {
+        let mut lines = parse::lines(block);
+        let header = lines.next().unwrap_or(block);
+
+        if !header.ends_with("map:") {
+            return Err(ParseError::at(source, header, "expected a map header"));
+        }
+
+        let mut ranges: Vec<Range> = Vec::new();
+
+        for line in lines {
+            let range = Range::parse(source, line)?;
+
+            if ranges.iter().any(|other| range.overlaps(other)) {
+                return Err(ParseError::at(source, line, "range overlaps another range of the map"));
+            }
+
+            ranges.push(range);
+        }
+
+        Ok(Self { ranges: ranges.into_iter().collect() })
     }
 }
 
@@ -265,6 +332,7 @@ impl<const N: usize> From<[Range; N]> for HumidityLocationMap {
     }
 }
 
+/// Seeds to plant along with the maps that convert a seed into its location
 #[derive(Debug, PartialEq, Eq)]
 pub struct Almanac<T>
 where
@@ -296,7 +364,11 @@ impl<T> Almanac<T>
 where
     T: Eq + Hash,
 {
-    fn convert(&self, seed: Seed) -> Conversion {
+    pub fn seeds(&self) -> impl Iterator<Item = &T> {
+        self.seeds.iter()
+    }
+
+    pub fn convert(&self, seed: Seed) -> Conversion {
         let soil = self.seed_soil.convert(seed);
         let fertilizer = self.soil_fertilizer.convert(soil);
         let water = self.fertilizer_water.convert(fertilizer);
@@ -328,90 +400,132 @@ impl Almanac<Seed> {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SeedRange {
-    start: u64,
-    end: u64,
+    pub start: u64,
+    pub end: u64,
 }
 
 impl SeedRange {
-    fn seeds(&self) -> impl Iterator<Item = Seed> {
-        (self.start..=self.end)
-            .into_iter()
-            .map(|n| Seed(n))
+    pub fn seeds(&self) -> impl Iterator<Item = Seed> {
+        (self.start..=self.end).map(Seed)
     }
 }
 
 impl Almanac<SeedRange> {
+    /// Conversion of every seed of every range, there are billions in the real almanac
     pub fn conversions(&self) -> impl Iterator<Item = Conversion> + '_ {
         self.seeds
             .iter()
-            .map(|r| r.seeds())
-            .flatten()
+            .flat_map(|r| r.seeds())
             .map(|s| self.convert(s))
     }
+
+    /// Lowest location of any seed, found by converting whole ranges of values at once
+    pub fn min_location(&self) -> Option<Location> {
+        let maps = [
+            &self.seed_soil.0,
+            &self.soil_fertilizer.0,
+            &self.fertilizer_water.0,
+            &self.water_light.0,
+            &self.light_temperature.0,
+            &self.temperature_humidity.0,
+            &self.humidity_location.0,
+        ];
+
+        let seeds = self.seeds.iter().map(|r| r.start..r.end + 1).collect();
+        let locations = maps.into_iter().fold(seeds, |values: Vec<_>, map| {
+            values
+                .into_iter()
+                .flat_map(|range| map.convert_range(range))
+                .collect()
+        });
+
+        locations.into_iter().map(|range| range.start).min().map(Location)
+    }
+}
+
+/// Strip the `seeds:` label from the first block of the almanac
+fn seed_values<'a>(source: &str, block: &'a str) -> Result<impl Iterator<Item = &'a str>, ParseError> {
+    Ok(parse::label(source, block, "seeds")?.split_whitespace())
 }
 
-fn parse_seeds(block: &str) -> HashSet<Seed> {
-    block
-        .split(" ")
-        .skip(1)
-        .map(|s| Seed(u64::from_str_radix(s, 10).expect("Could not parse seed")))
+fn parse_seeds(source: &str, block: &str) -> Result<HashSet<Seed>, ParseError> {
+    seed_values(source, block)?
+        .map(|s| parse::number(source, s).map(Seed))
         .collect()
 }
 
-impl<'a> From<One<'a>> for Almanac<Seed> {
-    fn from(input: One<'a>) -> Self {
-        let blocks: Vec<&str> = input.0.split("\n\n").collect();
+fn parse_seed_ranges(source: &str, block: &str) -> Result<HashSet<SeedRange>, ParseError> {
+    let mut values = seed_values(source, block)?;
+    let mut ranges = HashSet::new();
 
-        assert_eq!(blocks.len(), 8);
+    while let Some(start) = values.next() {
+        let Some(length) = values.next() else {
+            return Err(ParseError::at(source, start, "expected a length after the start of the seed range"));
+        };
 
-        Self {
-            seeds: parse_seeds(blocks[0]) ,
-            seed_soil: SeedSoilMap(Map::from(blocks[1])),
-            soil_fertilizer: SoilFertilizerMap(Map::from(blocks[2])),
-            fertilizer_water: FertilizerWaterMap(Map::from(blocks[3])),
-            water_light: WaterLightMap(Map::from(blocks[4])),
-            light_temperature: LightTemperatureMap(Map::from(blocks[5])),
-            temperature_humidity: TemperatureHumidityMap(Map::from(blocks[6])),
-            humidity_location: HumidityLocationMap(Map::from(blocks[7])),
+        let start_value: u64 = parse::number(source, start)?;
+        let length_value: u64 = parse::number(source, length)?;
+
+        if length_value == 0 {
+            return Err(ParseError::at(source, length, "expected a seed range of at least one seed"));
         }
+
+        // The range is stored with its last seed, but converted with the value past it
+        if start_value.checked_add(length_value).is_none() {
+            return Err(ParseError::at(source, length, "seed range goes past the largest value"));
+        }
+
+        ranges.insert(SeedRange { start: start_value, end: start_value + length_value - 1 });
     }
+
+    Ok(ranges)
 }
 
-fn parse_seed_ranges(block: &str) -> HashSet<SeedRange> {
-    let mut values = block
-        .split_whitespace()
-        .skip(1)
-        .map(|s| u64::from_str_radix(s, 10).expect("Could not parse seed"));
+fn parse_almanac<T, F>(input: &str, parse_seeds: F) -> Result<Almanac<T>, ParseError>
+where
+    T: Eq + Hash,
+    F: Fn(&str, &str) -> Result<HashSet<T>, ParseError>,
+{
+    let blocks: Vec<&str> = parse::blocks(input).collect();
 
-    let mut ranges = HashSet::new();
+    if blocks.len() != 8 {
+        let end = &input[input.len()..];
+        let message = format!("expected the seeds followed by 7 maps, found {} blocks", blocks.len());
 
-    while let Some(start) = values.next() {
-        let length = values.next().expect("Must be even number of values to produce seed ranges");
-        let end = start + length - 1;
+        return Err(ParseError::at(input, end, message));
+    }
+
+    let seeds = parse_seeds(input, blocks[0])?;
 
-        ranges.insert(SeedRange { start, end });
+    if seeds.is_empty() {
+        return Err(ParseError::at(input, blocks[0], "expected at least one seed"));
     }
 
-    ranges
+    Ok(Almanac {
+        seeds,
+        seed_soil: SeedSoilMap(Map::parse(input, blocks[1])?),
+        soil_fertilizer: SoilFertilizerMap(Map::parse(input, blocks[2])?),
+        fertilizer_water: FertilizerWaterMap(Map::parse(input, blocks[3])?),
+        water_light: WaterLightMap(Map::parse(input, blocks[4])?),
+        light_temperature: LightTemperatureMap(Map::parse(input, blocks[5])?),
+        temperature_humidity: TemperatureHumidityMap(Map::parse(input, blocks[6])?),
+        humidity_location: HumidityLocationMap(Map::parse(input, blocks[7])?),
+    })
 }
 
+impl<'a> TryFrom<One<'a>> for Almanac<Seed> {
+    type Error = ParseError;
 
-impl<'a> From<Two<'a>> for Almanac<SeedRange> {
-    fn from(input: Two<'a>) -> Self {
-        let blocks: Vec<&str> = input.0.split("\n\n").collect();
+    fn try_from(input: One<'a>) -> Result<Self, Self::Error> {
+        parse_almanac(input.0, parse_seeds)
+    }
+}
 
-        assert_eq!(blocks.len(), 8);
+impl<'a> TryFrom<Two<'a>> for Almanac<SeedRange> {
+    type Error = ParseError;
 
-        Self {
-            seeds: parse_seed_ranges(blocks[0]) ,
-            seed_soil: SeedSoilMap(Map::from(blocks[1])),
-            soil_fertilizer: SoilFertilizerMap(Map::from(blocks[2])),
-            fertilizer_water: FertilizerWaterMap(Map::from(blocks[3])),
-            water_light: WaterLightMap(Map::from(blocks[4])),
-            light_temperature: LightTemperatureMap(Map::from(blocks[5])),
-            temperature_humidity: TemperatureHumidityMap(Map::from(blocks[6])),
-            humidity_location: HumidityLocationMap(Map::from(blocks[7])),
-        }
+    fn try_from(input: Two<'a>) -> Result<Self, Self::Error> {
+        parse_almanac(input.0, parse_seed_ranges)
     }
 }
 
@@ -419,13 +533,19 @@ impl<'a> From<Two<'a>> for Almanac<SeedRange> {
 mod tests {
     use std::{collections::HashSet, hash::Hash};
 
+    use aoc::{Generate, ParseError};
     use indoc::indoc;
+    use proptest::collection::{hash_set, vec};
+    use proptest::prelude::*;
+
+    use crate::Day5;
 
     use super::{
         One,
         Two,
         Almanac,
         Seed,
+        Map,
         Range,
         SeedSoilMap,
         SoilFertilizerMap,
@@ -524,8 +644,71 @@ mod tests {
 
     #[test]
     fn parsing() {
-        assert_eq!(Almanac::from(One(INPUT)), seed_almanac());
-        assert_eq!(Almanac::from(Two(INPUT)), seed_range_almanac());
+        assert_eq!(Almanac::try_from(One(INPUT)), Ok(seed_almanac()));
+        assert_eq!(Almanac::try_from(Two(INPUT)), Ok(seed_range_almanac()));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = INPUT.replacen("37 52 2", "37 52", 1);
+
+        assert_eq!(
+            Almanac::try_from(One(&input)).err(),
+            Some(ParseError::new(9, 1, "37 52", "expected 3 numbers")),
+        );
+
+        let input = INPUT.replacen("52 50 48", "52 50 49", 1);
+
+        assert_eq!(
+            Almanac::try_from(One(&input)).err(),
+            Some(ParseError::new(5, 1, "52 50 49", "range overlaps another range of the map")),
+        );
+
+        let input = INPUT.replacen("50 98 2", "0 18446744073709551615 2", 1);
+
+        assert_eq!(
+            Almanac::try_from(One(&input)).err(),
+            Some(ParseError::new(4, 24, "2", "range goes past the largest value")),
+        );
+
+        let input = INPUT.replacen("50 98 2", "18446744073709551615 98 2", 1);
+
+        assert_eq!(
+            Almanac::try_from(Two(&input)).err(),
+            Some(ParseError::new(4, 25, "2", "range goes past the largest value")),
+        );
+
+        let input = INPUT.replacen("55 13", "55", 1);
+
+        assert_eq!(
+            Almanac::try_from(Two(&input)).err(),
+            Some(ParseError::new(1, 14, "55", "expected a length after the start of the seed range")),
+        );
+
+        let input = INPUT.replacen("55 13", "55 0", 1);
+
+        assert_eq!(
+            Almanac::try_from(Two(&input)).err(),
+            Some(ParseError::new(1, 17, "0", "expected a seed range of at least one seed")),
+        );
+
+        let input = INPUT.replacen("55 13", "18446744073709551615 1", 1);
+
+        assert_eq!(
+            Almanac::try_from(Two(&input)).err(),
+            Some(ParseError::new(1, 35, "1", "seed range goes past the largest value")),
+        );
+
+        let input = INPUT.replacen("seeds: 79 14 55 13", "seeds:", 1);
+
+        assert_eq!(
+            Almanac::try_from(One(&input)).err(),
+            Some(ParseError::new(1, 1, "seeds:", "expected at least one seed")),
+        );
+        assert_eq!(
+            Almanac::try_from(Two(&input)).err(),
+            Some(ParseError::new(1, 1, "seeds:", "expected at least one seed")),
+        );
     }
 
     #[test]
@@ -579,4 +762,116 @@ mod tests {
 
         assert_eq!(expected, conversions);
     }
+
+    #[test]
+    fn min_location() {
+        assert_eq!(seed_range_almanac().min_location(), Some(super::Location(46)));
+    }
+
+    /// Map whose ranges have sources that do not overlap, like in the real almanacs
+    fn map() -> impl Strategy<Value = Map> {
+        vec((0..30u64, 1..30u64, 0..300u64), 0..6).prop_map(|ranges| {
+            let mut src_start = 0;
+            let ranges = ranges
+                .into_iter()
+                .map(|(gap, length, dest_start)| {
+                    src_start += gap;
+                    let range = Range { dest_start, src_start, length };
+                    src_start += length;
+                    range
+                })
+                .collect();
+
+            Map { ranges }
+        })
+    }
+
+    fn seed_ranges() -> impl Strategy<Value = HashSet<SeedRange>> {
+        hash_set((0..300u64, 0..30u64), 1..4).prop_map(|ranges| {
+            ranges
+                .into_iter()
+                .map(|(start, length)| SeedRange { start, end: start + length })
+                .collect()
+        })
+    }
+
+    fn seed_range_almanacs() -> impl Strategy<Value = Almanac<SeedRange>> {
+        (seed_ranges(), [map(), map(), map(), map(), map(), map(), map()]).prop_map(|(seeds, maps)| {
+            let [a, b, c, d, e, f, g] = maps;
+
+            Almanac {
+                seeds,
+                seed_soil: SeedSoilMap(a),
+                soil_fertilizer: SoilFertilizerMap(b),
+                fertilizer_water: FertilizerWaterMap(c),
+                water_light: WaterLightMap(d),
+                light_temperature: LightTemperatureMap(e),
+                temperature_humidity: TemperatureHumidityMap(f),
+                humidity_location: HumidityLocationMap(g),
+            }
+        })
+    }
+
+    fn brute_force_min_location(almanac: &Almanac<SeedRange>) -> Option<u64> {
+        almanac.conversions().map(|c| c.location.into()).min()
+    }
+
+    proptest! {
+        #[test]
+        fn parsed_maps_convert_ranges_like_values(
+            lines in vec((0..100u64, 0..60u64, 0..20u64), 0..5),
+            start in 0..100u64,
+            length in 0..50u64,
+        ) {
+            let block: String = lines
+                .iter()
+                .map(|(dest, src, len)| format!("{} {} {}\n", dest, src, len))
+                .collect();
+            let block = format!("a-to-b map:\n{}", block);
+            let overlapping = lines.iter().enumerate().any(|(i, &(_, a, a_len))| {
+                lines[..i].iter().any(|&(_, b, b_len)| a < b + b_len && b < a + a_len)
+            });
+
+            match Map::parse(&block, &block) {
+                Ok(map) => {
+                    prop_assert!(!overlapping);
+
+                    let mut expected: Vec<u64> = (start..start + length).map(|n| map.convert(n)).collect();
+                    let mut converted: Vec<u64> = map.convert_range(start..start + length).into_iter().flatten().collect();
+
+                    expected.sort_unstable();
+                    converted.sort_unstable();
+
+                    prop_assert_eq!(converted, expected);
+                }
+                Err(err) => {
+                    prop_assert!(overlapping);
+                    prop_assert_eq!(err.message, "range overlaps another range of the map");
+                }
+            }
+        }
+
+        #[test]
+        fn range_conversion_matches_values(map in map(), start in 0..300u64, length in 0..100u64) {
+            let mut expected: Vec<u64> = (start..start + length).map(|n| map.convert(n)).collect();
+            let mut converted: Vec<u64> = map.convert_range(start..start + length).into_iter().flatten().collect();
+
+            expected.sort_unstable();
+            converted.sort_unstable();
+
+            prop_assert_eq!(converted, expected);
+        }
+
+        #[test]
+        fn min_location_matches_conversions(almanac in seed_range_almanacs()) {
+            prop_assert_eq!(almanac.min_location().map(u64::from), brute_force_min_location(&almanac));
+        }
+
+        #[test]
+        fn min_location_of_generated_almanacs(seed: u64, size in 1..8usize) {
+            let almanac = Almanac::try_from(Two(&Day5::generate(seed, size))).unwrap();
+
+            prop_assert_eq!(almanac.min_location().map(u64::from), brute_force_min_location(&almanac));
+        }
+    }
 }
@@ -0,0 +1,121 @@
+//! Random almanacs
+
+use aoc::Generate;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day5;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Values of each category are below `size` times this
+const SPREAD: u64 = 100;
+
+/// Seed ranges are at most this long, part 2 goes through every seed in them
+const MAX_SEED_RANGE: u64 = 100;
+
+/// Ranges of a map that splits `0..limit` into `n` pieces and moves them around
+///
+/// Like in the real almanacs, sources and destinations of different ranges never overlap. Pieces
+/// that end up in place may be left out, since values outside of every range map to themselves.
+fn map_ranges<R: Rng>(rng: &mut R, limit: u64, n: usize) -> Vec<(u64, u64, u64)> {
+    let mut cuts = rand::seq::index::sample(rng, limit as usize - 1, n - 1)
+        .into_iter()
+        .map(|cut| cut as u64 + 1)
+        .collect::<Vec<_>>();
+
+    cuts.push(0);
+    cuts.push(limit);
+    cuts.sort_unstable();
+
+    let pieces: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+    let mut order: Vec<usize> = (0..pieces.len()).collect();
+    order.shuffle(rng);
+
+    let mut destination = 0;
+    let mut ranges = Vec::new();
+
+    for i in order {
+        let (source, len) = pieces[i];
+        ranges.push((destination, source, len));
+        destination += len;
+    }
+
+    ranges.retain(|&(destination, source, _)| destination != source || rng.gen_bool(0.5));
+    ranges.shuffle(rng);
+    ranges
+}
+
+impl Generate for Day5 {
+    /// Almanac of `size` seed ranges and `size` ranges per map
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let size = size.max(1);
+        let limit = SPREAD * size as u64;
+
+        let seeds: Vec<String> = (0..size)
+            .flat_map(|_| {
+                let len = rng.gen_range(1..=MAX_SEED_RANGE);
+                [rng.gen_range(0..=limit - len).to_string(), len.to_string()]
+            })
+            .collect();
+
+        let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+        for name in MAPS {
+            almanac.push_str(&format!("\n{} map:\n", name));
+
+            for (destination, source, len) in map_ranges(&mut rng, limit, size) {
+                almanac.push_str(&format!("{} {} {}\n", destination, source, len));
+            }
+        }
+
+        almanac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Generate;
+
+    use crate::Day5;
+
+    use super::map_ranges;
+
+    #[test]
+    fn maps_are_permutations() {
+        let mut rng = <rand_chacha::ChaCha8Rng as rand::SeedableRng>::seed_from_u64(5);
+        let ranges = map_ranges(&mut rng, 1000, 10);
+        let mut mapped = vec![0; 1000];
+
+        for n in 0..1000u64 {
+            let to = ranges
+                .iter()
+                .find(|&&(_, source, len)| (source..source + len).contains(&n))
+                .map_or(n, |&(destination, source, _)| destination + n - source);
+
+            mapped[to as usize] += 1;
+        }
+
+        assert!(mapped.iter().all(|&count| count == 1));
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..10 {
+            let input = Day5::generate(seed, 10);
+
+            assert_eq!(input, Day5::generate(seed, 10));
+            aoc::solve::<Day5>(&input, None).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+        }
+    }
+}
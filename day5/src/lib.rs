@@ -0,0 +1,43 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+pub mod almanac;
+pub mod generate;
+
+use aoc::{ParseError, Solution};
+
+use almanac::{Almanac, Seed, SeedRange};
+
+/// Input interpreted according to the rules of part one, where seeds are listed individually
+pub struct One<'a>(pub &'a str);
+
+/// Input interpreted according to the rules of part two, where seeds are given as ranges
+pub struct Two<'a>(pub &'a str);
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input<'a> = (Almanac<Seed>, Almanac<SeedRange>);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok((Almanac::try_from(One(input))?, Almanac::try_from(Two(input))?))
+    }
+
+    fn part1((almanac, _): &Self::Input<'_>) -> u64 {
+        almanac
+            .conversions()
+            .map(|c| c.location.into())
+            .min()
+            .expect("Almanacs have at least one seed")
+    }
+
+    fn part2((_, almanac): &Self::Input<'_>) -> u64 {
+        almanac
+            .min_location()
+            .expect("Almanacs have at least one seed")
+            .into()
+    }
+}
@@ -0,0 +1,77 @@
+//! Random sheets of races
+
+use aoc::Generate;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day6;
+
+/// Part 2 joins the races into one, whose numbers must stay small enough to be exact as floats
+pub const MAX_RACES: usize = 4;
+
+/// Longest distance that can be travelled in a race of the given time
+fn best_distance(time: u64) -> u64 {
+    (time / 2) * (time - time / 2)
+}
+
+fn joined(values: &[u64]) -> u64 {
+    values
+        .iter()
+        .map(u64::to_string)
+        .collect::<String>()
+        .parse()
+        .expect("Joined values fit in a u64")
+}
+
+impl Generate for Day6 {
+    /// Sheet of `size` races, at most [`MAX_RACES`], each of which can be won
+    ///
+    /// The joined race of part 2 can be won as well.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let n_races = size.clamp(1, MAX_RACES);
+
+        loop {
+            let times: Vec<u64> = (0..n_races).map(|_| rng.gen_range(7..100)).collect();
+            let records: Vec<u64> = times
+                .iter()
+                .map(|&time| rng.gen_range(0..best_distance(time)))
+                .collect();
+
+            if joined(&records) >= best_distance(joined(&times)) {
+                continue;
+            }
+
+            let line = |values: &[u64]| {
+                values
+                    .iter()
+                    .map(|n| format!("{:>4}", n))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+
+            return format!("Time:     {}\nDistance: {}\n", line(&times), line(&records));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Generate, Solution};
+
+    use crate::Day6;
+
+    use super::MAX_RACES;
+
+    #[test]
+    fn generated() {
+        for seed in 0..50 {
+            let input = Day6::generate(seed, MAX_RACES);
+            let (races, race) = Day6::parse(&input).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+
+            assert_eq!(input, Day6::generate(seed, MAX_RACES));
+            assert_eq!(races.len(), MAX_RACES);
+            assert!(races.iter().chain([&race]).all(|race| race.n_better_times() > 0), "Seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Day 6: Wait For It
+
+pub mod generate;
+pub mod race;
+
+use aoc::{ParseError, Solution};
+
+use race::{Race, parse_races, parse_big_race};
+
+/// Product of the number of ways to beat the record of each race
+pub fn part1(races: &[Race]) -> usize {
+    races
+        .iter()
+        .map(|r| r.n_better_times())
+        .product()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    type Input<'a> = (Vec<Race>, Race);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok((parse_races(input)?, parse_big_race(input)?))
+    }
+
+    fn part1((races, _): &Self::Input<'_>) -> usize {
+        part1(races)
+    }
+
+    fn part2((_, race): &Self::Input<'_>) -> usize {
+        race.n_better_times()
+    }
+}
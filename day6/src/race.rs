@@ -1,3 +1,5 @@
+use aoc::{ParseError, parse};
+
 #[derive(Debug)]
 pub struct Race {
     pub duration_ms: f64,
@@ -26,59 +28,80 @@ impl Race {
     }
 }
 
-fn parse_split_number(line: &str) -> f64 {
+/// Values of the `Time:` and `Distance:` lines, which must appear in that order
+fn labelled_lines(input: &str) -> Result<(&str, &str), ParseError> {
+    let mut lines = parse::lines(input);
+    let mut values = |label: &str| {
+        let line = lines.next().unwrap_or(&input[input.len()..]);
+
+        parse::label(input, line, label)
+    };
+
+    let times = values("Time")?;
+    let distances = values("Distance")?;
+
+    if let Some(line) = lines.next() {
+        return Err(ParseError::near(input, line, "unexpected text after distances"));
+    }
+
+    Ok((times, distances))
+}
+
+fn parse_numbers(input: &str, values: &str) -> Result<Vec<f64>, ParseError> {
+    let numbers: Vec<u64> = parse::numbers(input, values)?;
+
+    Ok(numbers.into_iter().map(|n| n as f64).collect())
+}
+
+fn parse_split_number(input: &str, values: &str) -> Result<f64, ParseError> {
     let mut combined = String::new();
 
-    for s in line.split_whitespace().skip(1) {
+    for s in values.split_whitespace() {
+        parse::number::<u64>(input, s)?;
         combined.push_str(s);
     }
 
-    combined.parse().expect("Could not parse time")
+    combined
+        .parse()
+        .map_err(|_| ParseError::at(input, values.trim(), "expected a number"))
 }
 
-pub fn parse_big_race(input: &str) -> Race {
-    let lines: Vec<&str> = input.lines().collect();
-    
-    assert_eq!(lines.len(), 2);
-
-    let time = parse_split_number(lines[0]);
-    let distance = parse_split_number(lines[1]);
+pub fn parse_big_race(input: &str) -> Result<Race, ParseError> {
+    let (times, distances) = labelled_lines(input)?;
+    let time = parse_split_number(input, times)?;
+    let distance = parse_split_number(input, distances)?;
 
-    Race {
+    Ok(Race {
         duration_ms: time,
         record_mm: distance
-    }
+    })
 }
 
-pub fn parse_races(input: &str) -> Vec<Race> {
-    let lines: Vec<&str> = input.lines().collect();
-    
-    assert_eq!(lines.len(), 2);
+pub fn parse_races(input: &str) -> Result<Vec<Race>, ParseError> {
+    let (times, distances) = labelled_lines(input)?;
 
-    let times_ms: Vec<f64> = lines[0]
-        .split_whitespace()
-        .skip(1)
-        .map(|s| s.parse().expect("Could not parse number"))
-        .collect();
-
-    let distances_mm: Vec<f64> = lines[1]
-        .split_whitespace()
-        .skip(1)
-        .map(|s| s.parse().expect("Could not parse number"))
-        .collect();
+    let times_ms = parse_numbers(input, times)?;
+    let distances_mm = parse_numbers(input, distances)?;
 
-    assert_eq!(times_ms.len(), distances_mm.len());
+    if times_ms.len() != distances_mm.len() {
+        let message = format!("expected {} distances, found {}", times_ms.len(), distances_mm.len());
+        return Err(ParseError::at(input, distances.trim(), message));
+    }
 
-    times_ms
+    let races = times_ms
         .into_iter()
         .zip(distances_mm)
         .map(|(duration_ms, record_mm)| Race { duration_ms, record_mm })
-        .collect()
+        .collect();
+
+    Ok(races)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Race;
+    use aoc::ParseError;
+
+    use super::{Race, parse_big_race, parse_races};
 
     #[test]
     fn n_better_times() {
@@ -91,4 +114,21 @@ mod tests {
         let race = Race { duration_ms: 15f64, record_mm: 40f64 };
         assert_eq!(race.n_better_times(), 8);
     }
+
+    #[test]
+    fn parse_errors() {
+        let input = "Time:      7  15   30\nDistance:  9  40\n";
+
+        assert_eq!(
+            parse_races(input).err(),
+            Some(ParseError::new(2, 12, "9  40", "expected 3 distances, found 2")),
+        );
+
+        let input = "Time:      7  15   30\nDistance:  9  4O  200\n";
+
+        assert_eq!(
+            parse_big_race(input).err(),
+            Some(ParseError::new(2, 15, "4O", "expected a number")),
+        );
+    }
 }
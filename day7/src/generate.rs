@@ -0,0 +1,49 @@
+//! Random lists of hands
+
+use aoc::Generate;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day7;
+
+const CARDS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+/// Hand of five cards, drawn from a few labels so that every kind of hand comes up
+fn hand<R: Rng>(rng: &mut R) -> String {
+    let n_labels = rng.gen_range(1..=5);
+    let labels: Vec<char> = CARDS.choose_multiple(rng, n_labels).copied().collect();
+
+    (0..5)
+        .map(|_| *labels.choose(rng).expect("Labels are not empty"))
+        .collect()
+}
+
+impl Generate for Day7 {
+    /// List of `size` hands and their bids
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        (0..size)
+            .map(|_| format!("{} {}\n", hand(&mut rng), rng.gen_range(1..=1000)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Generate;
+
+    use crate::Day7;
+
+    #[test]
+    fn generated() {
+        for seed in 0..20 {
+            let input = Day7::generate(seed, 500);
+
+            assert_eq!(input, Day7::generate(seed, 500));
+            assert_eq!(input.lines().count(), 500);
+            aoc::solve::<Day7>(&input, None).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+        }
+    }
+}
@@ -0,0 +1,432 @@
+//! Day 7: Camel Cards
+
+pub mod generate;
+
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use aoc::stream::{self, ReadError, Stream};
+use aoc::{ParseError, Solution, parse};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl TryFrom<char> for Card {
+    type Error = char;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        let card = match input {
+            'A' => Self::Ace,
+            'K' => Self::King,
+            'Q' => Self::Queen,
+            'J' => Self::Jack,
+            'T' => Self::Ten,
+            '9' => Self::Nine,
+            '8' => Self::Eight,
+            '7' => Self::Seven,
+            '6' => Self::Six,
+            '5' => Self::Five,
+            '4' => Self::Four,
+            '3' => Self::Three,
+            '2' => Self::Two,
+            _ => return Err(input),
+        };
+
+        Ok(card)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: [Card; 5],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandKind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl Hand {
+    pub fn kind(&self) -> HandKind {
+        let mut groups: HashMap<&Card, u8> = HashMap::new();
+
+        for card in &self.cards {
+            *groups.entry(card).or_insert(0) += 1;
+        }
+
+        if groups.len() > 1 {
+            let &best_card = groups
+                .keys()
+                .filter(|&&card| *card != Card::Joker)
+                .max_by_key(|&&card| groups.get(card).copied().unwrap_or(0))
+                .unwrap();
+
+            let joker_count = groups
+                .get(&Card::Joker)
+                .copied()
+                .unwrap_or(0);
+
+            groups.entry(best_card).and_modify(|count| *count += joker_count);
+            groups.remove(&Card::Joker);
+        }
+
+        let mut counts = groups
+            .into_values()
+            .collect::<Vec<_>>();
+
+        counts.sort();
+        counts.reverse();
+
+        match counts.as_slice() {
+            [5] => HandKind::FiveOfAKind,
+            [4, 1] => HandKind::FourOfAKind,
+            [3, 2] => HandKind::FullHouse,
+            [3, 1, 1] => HandKind::ThreeOfAKind,
+            [2, 2, 1] => HandKind::TwoPair,
+            [2, 1, 1, 1] => HandKind::OnePair,
+            [1, 1, 1, 1, 1] => HandKind::HighCard,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Input interpreted according to the rules of part one, where J is a jack
+pub struct One<'a>(pub &'a str);
+
+/// Input interpreted according to the rules of part two, where J is a joker
+pub struct Two<'a>(pub &'a str);
+
+impl<'a> TryFrom<One<'a>> for Hand {
+    type Error = ParseError;
+
+    fn try_from(input: One<'a>) -> Result<Self, Self::Error> {
+        let text = input.0;
+        let cards = parse::chars(text, text, |c| Card::try_from(c).map_err(|_| "unknown card"))?
+            .try_into()
+            .map_err(|_| ParseError::at(text, text, "expected 5 cards"))?;
+
+        Ok(Hand { cards })
+    }
+}
+
+impl<'a> TryFrom<Two<'a>> for Hand {
+    type Error = ParseError;
+
+    fn try_from(input: Two<'a>) -> Result<Self, Self::Error> {
+        let mut hand = Hand::try_from(One(input.0))?;
+
+        for card in &mut hand.cards {
+            if *card == Card::Jack {
+                *card = Card::Joker;
+            }
+        }
+
+        Ok(hand)
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let self_kind = self.kind();
+        let other_kind = other.kind();
+
+        if self_kind != other_kind {
+            self_kind.cmp(&other_kind)
+        } else {
+            self.cards.cmp(&other.cards)
+        }
+    }
+}
+
+/// A hand along with the amount that was bet on it
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    pub hand: Hand,
+    pub bet: usize
+}
+
+impl PartialOrd for Game {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Game {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.hand.cmp(&other.hand)
+    }
+}
+
+impl Game {
+    /// Parse a line made of a hand and a bet, using `hand` to interpret the cards
+    fn parse<F>(line: &str, hand: F) -> Result<Self, ParseError>
+    where
+        F: Fn(&str) -> Result<Hand, ParseError>,
+    {
+        let mut fields = line.split_whitespace();
+        let end = &line[line.len()..];
+        let cards = fields
+            .next()
+            .ok_or_else(|| ParseError::at(line, end, "expected a hand"))?;
+        let bet = fields
+            .next()
+            .ok_or_else(|| ParseError::at(line, end, "expected a bet"))?;
+
+        if let Some(extra) = fields.next() {
+            return Err(ParseError::at(line, extra, "unexpected text after bet"));
+        }
+
+        Ok(Game {
+            hand: hand(cards).map_err(|err| err.within(line, cards))?,
+            bet: parse::number(line, bet)?,
+        })
+    }
+}
+
+impl<'a> TryFrom<One<'a>> for Game {
+    type Error = ParseError;
+
+    fn try_from(value: One<'a>) -> Result<Self, Self::Error> {
+        Game::parse(value.0, |cards| Hand::try_from(One(cards)))
+    }
+}
+
+impl<'a> TryFrom<Two<'a>> for Game {
+    type Error = ParseError;
+
+    fn try_from(value: Two<'a>) -> Result<Self, Self::Error> {
+        Game::parse(value.0, |cards| Hand::try_from(Two(cards)))
+    }
+}
+
+/// Every game played, ordered from the weakest hand to the strongest
+#[derive(Debug)]
+pub struct Games(Vec<Game>);
+
+impl Games {
+    pub fn iter(&self) -> impl Iterator<Item = &Game> {
+        self.0.iter()
+    }
+
+    pub fn total_winnings(&self) -> usize {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(rank, game)| (rank + 1) * game.bet)
+            .sum()
+    }
+}
+
+impl Games {
+    fn parse<F>(input: &str, game: F) -> Result<Self, ParseError>
+    where
+        F: Fn(&str) -> Result<Game, ParseError>,
+    {
+        parse::lines(input)
+            .map(|line| game(line).map_err(|err| err.within(input, line)))
+            .collect()
+    }
+}
+
+impl FromIterator<Game> for Games {
+    fn from_iter<I: IntoIterator<Item = Game>>(iter: I) -> Self {
+        let mut games: Vec<Game> = iter.into_iter().collect();
+
+        games.sort();
+
+        Self(games)
+    }
+}
+
+impl<'a> TryFrom<One<'a>> for Games {
+    type Error = ParseError;
+
+    fn try_from(value: One<'a>) -> Result<Self, Self::Error> {
+        Games::parse(value.0, |line| Game::try_from(One(line)))
+    }
+}
+
+impl<'a> TryFrom<Two<'a>> for Games {
+    type Error = ParseError;
+
+    fn try_from(value: Two<'a>) -> Result<Self, Self::Error> {
+        Games::parse(value.0, |line| Game::try_from(Two(line)))
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    type Input<'a> = (Games, Games);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok((Games::try_from(One(input))?, Games::try_from(Two(input))?))
+    }
+
+    fn part1((games, _): &Self::Input<'_>) -> usize {
+        games.total_winnings()
+    }
+
+    fn part2((_, games): &Self::Input<'_>) -> usize {
+        games.total_winnings()
+    }
+}
+
+/// Games are ranked against each other, so every game is kept but only once its line is parsed
+impl Stream for Day7 {
+    fn stream<R: BufRead>(reader: R) -> Result<(usize, usize), ReadError> {
+        let (games1, games2): (Vec<Game>, Vec<Game>) = stream::lines(reader, |line| {
+            Ok((Game::try_from(One(line))?, Game::try_from(Two(line))?))
+        })
+        .collect::<Result<_, _>>()?;
+
+        let games1: Games = games1.into_iter().collect();
+        let games2: Games = games2.into_iter().collect();
+
+        Ok((games1.total_winnings(), games2.total_winnings()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::ParseError;
+    use indoc::indoc;
+
+    use super::{Card, Hand, HandKind, Games, One, Two};
+
+    #[test]
+    fn ord() {
+        let stronger = Hand {
+            cards: [Card::Three, Card::Three, Card::Three, Card::Three, Card::Two],
+        };
+
+        let weaker = Hand {
+            cards: [Card::Two, Card::Ace, Card::Ace, Card::Ace, Card::Ace],
+        };
+
+        assert!(stronger > weaker);
+
+        let stronger = Hand {
+            cards: [Card::Seven, Card::Seven, Card::Eight, Card::Eight, Card::Eight],
+        };
+
+        let weaker = Hand {
+            cards: [Card::Seven, Card::Seven, Card::Seven, Card::Eight, Card::Eight],
+        };
+
+        assert!(stronger > weaker);
+    }
+
+    #[test]
+    fn kind() {
+        let five_of_a_kind = Hand {
+            cards: [Card::Ace, Card::Ace, Card::Ace, Card::Ace, Card::Ace],
+        };
+
+        assert_eq!(five_of_a_kind.kind(), HandKind::FiveOfAKind);
+
+        let four_of_a_kind = Hand {
+            cards: [Card::Ace, Card::Ace, Card::Eight, Card::Ace, Card::Ace],
+        };
+
+        assert_eq!(four_of_a_kind.kind(), HandKind::FourOfAKind);
+
+        let full_house = Hand {
+            cards: [Card::Two, Card::Three, Card::Three, Card::Three, Card::Two],
+        };
+
+        assert_eq!(full_house.kind(), HandKind::FullHouse);
+
+        let two_pair = Hand {
+            cards: [Card::Two, Card::Three, Card::Four, Card::Three, Card::Two],
+        };
+
+        assert_eq!(two_pair.kind(), HandKind::TwoPair);
+
+        let one_pair = Hand {
+            cards: [Card::Ace, Card::Two, Card::Three, Card::Ace, Card::Four],
+        };
+
+        assert_eq!(one_pair.kind(), HandKind::OnePair);
+
+        let high_card = Hand {
+            cards: [Card::Two, Card::Three, Card::Four, Card::Five, Card::Six],
+        };
+
+        assert_eq!(high_card.kind(), HandKind::HighCard);
+    }
+
+    #[test]
+    fn total_winnings() {
+        let input = indoc!{"
+            32T3K 765
+            T55J5 684
+            KK677 28
+            KTJJT 220
+            QQQJA 483
+        "};
+
+        let games = Games::try_from(One(input)).unwrap();
+
+        assert_eq!(games.total_winnings(), 6440);
+
+        let games = Games::try_from(Two(input)).unwrap();
+
+        assert_eq!(games.total_winnings(), 5905)
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = "32T3K 765\nT55J5 684\nKK6X7 28\n";
+
+        assert_eq!(
+            Games::try_from(One(input)).err(),
+            Some(ParseError::new(3, 4, "X", "unknown card")),
+        );
+
+        let input = "32T3K 765\nT55J 684\n";
+
+        assert_eq!(
+            Games::try_from(Two(input)).err(),
+            Some(ParseError::new(2, 1, "T55J", "expected 5 cards")),
+        );
+
+        let input = "32T3K 765\nT55J5 6e4\n";
+
+        assert_eq!(
+            Games::try_from(One(input)).err(),
+            Some(ParseError::new(2, 7, "6e4", "expected a number")),
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Random maps of the wasteland
+
+use std::collections::HashSet;
+
+use aoc::Generate;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day8;
+
+const MAX_GHOSTS: usize = 6;
+const NAME_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Unique node names, ending in A or Z only when asked to
+struct Names {
+    rng: ChaCha8Rng,
+    used: HashSet<String>,
+}
+
+impl Names {
+    fn next(&mut self, last: Option<char>) -> String {
+        loop {
+            let mut name: String = (0..2)
+                .map(|_| *NAME_CHARS.choose(&mut self.rng).expect("Name characters are not empty") as char)
+                .collect();
+
+            name.push(last.unwrap_or_else(|| loop {
+                let c = *NAME_CHARS.choose(&mut self.rng).expect("Name characters are not empty") as char;
+
+                if c != 'A' && c != 'Z' {
+                    break c;
+                }
+            }));
+
+            if self.used.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
+
+impl Generate for Day8 {
+    /// Map with up to `size` directions, where each ghost walks up to `size` times through them
+    ///
+    /// Every ghost follows its own cycle from its start node to its end node and back to the
+    /// second node of the cycle, like in the real maps. The first ghost goes from `AAA` to `ZZZ`.
+    /// Taking the wrong turn leads to decoy nodes that never reach an end node.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let size = size.max(1);
+        let directions: Vec<char> = (0..rng.gen_range(1..=size))
+            .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+            .collect();
+
+        let n_ghosts = rng.gen_range(1..=MAX_GHOSTS);
+        let laps: Vec<usize> = (0..n_ghosts).map(|_| rng.gen_range(1..=size)).collect();
+
+        let mut names = Names {
+            rng: ChaCha8Rng::seed_from_u64(rng.gen()),
+            used: HashSet::from(["AAA".to_string(), "ZZZ".to_string()]),
+        };
+
+        let decoys: Vec<String> = (0..size).map(|_| names.next(None)).collect();
+        let mut nodes: Vec<(String, String, String)> = decoys
+            .iter()
+            .map(|decoy| {
+                let mut next = || decoys.choose(&mut rng).expect("Decoys are not empty").clone();
+                (decoy.clone(), next(), next())
+            })
+            .collect();
+
+        for (ghost, laps) in laps.into_iter().enumerate() {
+            let (start, end) = match ghost {
+                0 => ("AAA".to_string(), "ZZZ".to_string()),
+                _ => (names.next(Some('A')), names.next(Some('Z'))),
+            };
+
+            let steps = directions.len() * laps;
+            let mut cycle = vec![start];
+            cycle.extend((1..steps).map(|_| names.next(None)));
+            cycle.push(end);
+
+            for step in 0..steps {
+                let next = cycle[step + 1].clone();
+                let decoy = decoys.choose(&mut rng).expect("Decoys are not empty").clone();
+                let (left, right) = match directions[step % directions.len()] {
+                    'L' => (next, decoy),
+                    _ => (decoy, next),
+                };
+
+                nodes.push((cycle[step].clone(), left, right));
+            }
+
+            // The end node leads to the same nodes as the start, so the ghost goes round again
+            let (_, left, right) = nodes[nodes.len() - steps].clone();
+            nodes.push((cycle[steps].clone(), left, right));
+        }
+
+        nodes.shuffle(&mut rng);
+
+        let mut map: String = directions.into_iter().collect();
+        map.push_str("\n\n");
+
+        for (node, left, right) in nodes {
+            map.push_str(&format!("{} = ({}, {})\n", node, left, right));
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Generate, Solution};
+
+    use crate::Day8;
+
+    #[test]
+    fn generated() {
+        for seed in 0..20 {
+            let input = Day8::generate(seed, 10);
+
+            assert_eq!(input, Day8::generate(seed, 10));
+            assert!(input.contains("\nAAA = ("));
+            assert!(input.contains("\nZZZ = ("));
+
+            let parsed = Day8::parse(&input).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+            let directions = input.lines().next().unwrap().len();
+
+            assert_eq!(Day8::part1(&parsed) % directions, 0, "Seed {}", seed);
+            assert_eq!(Day8::part2(&parsed) % Day8::part1(&parsed), 0, "Seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Day 8: Haunted Wasteland
+
+pub mod generate;
+
+use std::collections::HashMap;
+
+use aoc::{ParseError, Solution, parallel, parse};
+
+pub enum Direction {
+    Left,
+    Right,
+}
+
+pub fn parse_directions(line: &str) -> Result<Vec<Direction>, ParseError> {
+    let directions = line.trim();
+
+    if directions.is_empty() {
+        return Err(ParseError::at(line, directions, "expected directions"));
+    }
+
+    parse::chars(line, directions, |c| match c {
+        'R' => Ok(Direction::Right),
+        'L' => Ok(Direction::Left),
+        _ => Err("unknown direction"),
+    })
+}
+
+/// Left and right node reachable from each node
+pub struct Network<'a>(HashMap<&'a str, (&'a str, &'a str)>);
+
+impl<'a> Network<'a> {
+    pub fn nodes(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.0.keys().copied()
+    }
+
+    /// Number of steps taken from `start` until a node satisfying `pred` is reached
+    pub fn path_length<F>(&self, directions: &[Direction], start: &'a str, pred: F) -> usize
+    where
+        F: Fn(&str) -> bool,
+    {
+        directions
+            .iter()
+            .cycle()
+            .scan(start, |current_location, dir| {
+                if pred(current_location) {
+                    return None;
+                }
+
+                let (left, right) = self
+                    .0
+                    .get(current_location)
+                    .expect("Nodes only lead to nodes of the network");
+
+                *current_location = match dir {
+                    Direction::Left => left,
+                    Direction::Right => right,
+                };
+
+                Some(())
+            })
+            .count()
+    }
+}
+
+fn node_name<'a>(source: &str, text: &'a str) -> Result<&'a str, ParseError> {
+    if text.is_empty() || !text.chars().all(char::is_alphanumeric) {
+        return Err(ParseError::near(source, text, "expected a node name"));
+    }
+
+    Ok(text)
+}
+
+/// Parse a line such as `AAA = (BBB, CCC)`, `source` is the full input the line was taken from
+fn parse_node<'a>(source: &str, line: &'a str) -> Result<(&'a str, (&'a str, &'a str)), ParseError> {
+    let (name, options) = line
+        .split_once('=')
+        .ok_or_else(|| ParseError::at(source, &line[line.len()..], "expected \" = \""))?;
+
+    let name = node_name(source, name.trim_end())?;
+    let options = options.trim_start();
+    let expected = || ParseError::near(source, options, "expected \"(LEFT, RIGHT)\"");
+    let (left, right) = options
+        .strip_prefix('(')
+        .and_then(|options| options.split_once(", "))
+        .ok_or_else(expected)?;
+    let (right, rest) = right.split_once(')').ok_or_else(expected)?;
+
+    parse::end(source, rest, "node")?;
+
+    Ok((name, (node_name(source, left)?, node_name(source, right)?)))
+}
+
+impl<'a> TryFrom<&'a str> for Network<'a> {
+    type Error = ParseError;
+
+    /// Parse the nodes, every one of which must lead to nodes that are defined
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let nodes: Vec<_> = parse::lines(input)
+            .map(|line| parse_node(input, line))
+            .collect::<Result<_, _>>()?;
+
+        let network: HashMap<_, _> = nodes.iter().copied().collect();
+
+        for (_, (left, right)) in nodes {
+            if let Some(name) = [left, right].into_iter().find(|name| !network.contains_key(name)) {
+                return Err(ParseError::at(input, name, "undefined node"));
+            }
+        }
+
+        Ok(Self(network))
+    }
+}
+
+/// Number of steps from AAA to ZZZ
+pub fn part1(network: &Network<'_>, directions: &[Direction]) -> usize {
+    network.path_length(directions, "AAA", |node| node == "ZZZ")
+}
+
+/// Number of steps until every ghost starting on a node ending in A is on a node ending in Z
+pub fn part2(network: &Network<'_>, directions: &[Direction]) -> usize {
+    let starts: Vec<&str> = network
+        .nodes()
+        .filter(|node| node.ends_with("A"))
+        .collect();
+
+    parallel::map(&starts, |node| network.path_length(directions, node, |n| n.ends_with("Z")))
+        .into_iter()
+        .reduce(num::integer::lcm)
+        .expect("AAA is a start node")
+}
+
+/// Parse the directions and the network, which must have the `AAA` and `ZZZ` nodes
+pub fn parse_problem(input: &str) -> Result<(Vec<Direction>, Network<'_>), ParseError> {
+    let (first, nodes) = input.split_once('\n').unwrap_or((input, ""));
+    let directions = parse_directions(first).map_err(|err| err.within(input, first))?;
+    let network = Network::try_from(nodes).map_err(|err| err.within(input, nodes))?;
+
+    for name in ["AAA", "ZZZ"] {
+        if !network.0.contains_key(name) {
+            return Err(ParseError::at(input, &input[input.len()..], format!("missing node {}", name)));
+        }
+    }
+
+    Ok((directions, network))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type Input<'a> = (Vec<Direction>, Network<'a>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_problem(input)
+    }
+
+    fn part1((directions, network): &Self::Input<'_>) -> usize {
+        part1(network, directions)
+    }
+
+    fn part2((directions, network): &Self::Input<'_>) -> usize {
+        part2(network, directions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::ParseError;
+    use indoc::indoc;
+
+    use super::{Network, parse_directions, parse_problem, part1, part2};
+
+    #[test]
+    fn test_part1() {
+        let input = indoc!{"
+            RL
+
+            AAA = (BBB, CCC)
+            BBB = (DDD, EEE)
+            CCC = (ZZZ, GGG)
+            DDD = (DDD, DDD)
+            EEE = (EEE, EEE)
+            GGG = (GGG, GGG)
+            ZZZ = (ZZZ, ZZZ)
+        "};
+        
+        let (directions, network) = parse_problem(input).unwrap();
+
+        assert_eq!(part1(&network, &directions), 2);
+
+        let input = indoc!{"
+            LLR
+
+            AAA = (BBB, BBB)
+            BBB = (AAA, ZZZ)
+            ZZZ = (ZZZ, ZZZ)
+        "};
+        
+        let (directions, network) = parse_problem(input).unwrap();
+
+        assert_eq!(part1(&network, &directions), 6);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = indoc!{"
+            LR
+
+            11A = (11B, XXX)
+            11B = (XXX, 11Z)
+            11Z = (11B, XXX)
+            22A = (22B, XXX)
+            22B = (22C, 22C)
+            22C = (22Z, 22Z)
+            22Z = (22B, 22B)
+            XXX = (XXX, XXX)
+        "};
+
+        // The example has no AAA node, so it cannot be parsed as a whole puzzle
+        let (first, nodes) = input.split_once("\n\n").unwrap();
+        let directions = parse_directions(first).unwrap();
+        let network = Network::try_from(nodes).unwrap();
+
+        assert_eq!(part2(&network, &directions), 6);
+        assert_eq!(parse_problem(input).err(), Some(ParseError::new(11, 1, "", "missing node AAA")));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = indoc!{"
+            LLX
+
+            AAA = (BBB, BBB)
+        "};
+
+        assert_eq!(parse_problem(input).err(), Some(ParseError::new(1, 3, "X", "unknown direction")));
+
+        let input = indoc!{"
+            LLR
+
+            AAA = (BBB, BBB)
+            BBB = AAA, ZZZ
+        "};
+
+        assert_eq!(
+            parse_problem(input).err(),
+            Some(ParseError::new(4, 7, "AAA,", "expected \"(LEFT, RIGHT)\"")),
+        );
+        assert_eq!(
+            parse_problem("LR\n\nAAA = (BBB, CCC) x\n").err(),
+            Some(ParseError::new(3, 18, "x", "unexpected text after node")),
+        );
+        assert_eq!(
+            parse_problem("LR\n\nA-A = (BBB, CCC)\n").err(),
+            Some(ParseError::new(3, 1, "A-A", "expected a node name")),
+        );
+        assert_eq!(
+            parse_problem("LR\n\nAAA = (ZZZ, BBB)\nZZZ = (ZZZ, ZZZ)\n").err(),
+            Some(ParseError::new(3, 13, "BBB", "undefined node")),
+        );
+        assert_eq!(
+            parse_problem("LR\n\nAAA = (AAA, AAA)\n").err(),
+            Some(ParseError::new(4, 1, "", "missing node ZZZ")),
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Random reports of polynomial histories
+
+use aoc::Generate;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Day9;
+
+const MAX_DEGREE: usize = 5;
+
+/// Value of the polynomial with the given coefficients, lowest degree first
+fn evaluate(coefficients: &[i64], x: i64) -> i64 {
+    coefficients.iter().rev().fold(0, |value, &c| value * x + c)
+}
+
+/// Coefficients of a polynomial whose degree leaves at least two rounds of differences
+///
+/// Its values over `len` steps then reduce to zeroes, as the puzzle requires.
+fn polynomial<R: Rng>(rng: &mut R, len: usize) -> Vec<i64> {
+    let degree = rng.gen_range(0..=MAX_DEGREE.min(len.saturating_sub(2)));
+
+    (0..=degree).map(|_| rng.gen_range(-9..=9)).collect()
+}
+
+impl Generate for Day9 {
+    /// Report of `size` histories, each holding the values of a polynomial over 5 to 21 steps
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let len = rng.gen_range(5..=21);
+
+        (0..size)
+            .map(|_| {
+                let coefficients = polynomial(&mut rng, len);
+                let values: Vec<String> = (0..len as i64)
+                    .map(|x| evaluate(&coefficients, x).to_string())
+                    .collect();
+
+                values.join(" ") + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Generate;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::Day9;
+    use crate::oasis::History;
+
+    use super::{evaluate, polynomial};
+
+    #[test]
+    fn extrapolate_polynomials() {
+        let mut rng = ChaCha8Rng::seed_from_u64(9);
+
+        for len in 2..=21 {
+            let coefficients = polynomial(&mut rng, len);
+            let history = History::new((0..len as i64).map(|x| evaluate(&coefficients, x)));
+
+            assert_eq!(history.extrapolate_end(), evaluate(&coefficients, len as i64), "{:?}", coefficients);
+            assert_eq!(history.extrapolate_start(), evaluate(&coefficients, -1), "{:?}", coefficients);
+        }
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..20 {
+            let input = Day9::generate(seed, 200);
+
+            assert_eq!(input, Day9::generate(seed, 200));
+            assert_eq!(input.lines().count(), 200);
+            aoc::solve::<Day9>(&input, None).unwrap_or_else(|err| panic!("Seed {}: {}", seed, err));
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Day 9: Mirage Maintenance
+
+pub mod generate;
+pub mod oasis;
+
+use std::io::BufRead;
+
+use aoc::stream::{self, ReadError, Stream};
+use aoc::{ParseError, Solution, parallel};
+
+use oasis::{History, Report};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    type Input<'a> = Report;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Report::try_from(input)
+    }
+
+    fn part1(report: &Self::Input<'_>) -> i64 {
+        parallel::sum(report.histories(), History::extrapolate_end)
+    }
+
+    fn part2(report: &Self::Input<'_>) -> i64 {
+        parallel::sum(report.histories(), History::extrapolate_start)
+    }
+}
+
+impl Stream for Day9 {
+    fn stream<R: BufRead>(reader: R) -> Result<(i64, i64), ReadError> {
+        stream::lines(reader, |line| History::try_from(line))
+            .try_fold((0, 0), |(end, start), history| {
+                let history = history?;
+                Ok((end + history.extrapolate_end(), start + history.extrapolate_start()))
+            })
+    }
+}
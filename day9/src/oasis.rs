@@ -1,9 +1,15 @@
+use aoc::{ParseError, parse};
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Report {
     dataset: Vec<History>,
 }
 
 impl Report {
+    pub fn histories(&self) -> &[History] {
+        &self.dataset
+    }
+
     pub fn extrapolate_end(&self) -> impl Iterator<Item = i64> + '_ {
         self.dataset
             .iter()
@@ -17,21 +23,21 @@ impl Report {
     }
 }
 
-impl From<&str> for Report {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Report {
+    type Error = ParseError;
 
-        Self {
-            dataset: input
-                .trim()
-                .lines()
-                .map(|line| History::from(line))
-                .collect(),
-        }
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let dataset = parse::lines(input)
+            .map(|line| History::try_from(line).map_err(|err| err.within(input, line)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { dataset })
     }
 }
 
+/// Sequence of readings of a single value over time
 #[derive(Debug, PartialEq, Eq)]
-struct History {
+pub struct History {
     values: Vec<i64>,
 }
 
@@ -49,7 +55,7 @@ fn differences(values: &[i64]) -> Vec<i64> {
 }
 
 impl History {
-    fn new<I>(values: I) -> Self
+    pub fn new<I>(values: I) -> Self
     where
         I: IntoIterator<Item = i64>
     {
@@ -58,7 +64,7 @@ impl History {
         }
     }
 
-    fn extrapolate_end(&self) -> i64 {
+    pub fn extrapolate_end(&self) -> i64 {
         let mut diff_seq = differences(&self.values);
         let mut ends = Vec::new();
 
@@ -72,7 +78,7 @@ impl History {
         ends.into_iter().sum()
     }
 
-    fn extrapolate_start(&self) -> i64 {
+    pub fn extrapolate_start(&self) -> i64 {
         let mut diff_seq = differences(&self.values);
         let mut starts = Vec::new();
 
@@ -90,20 +96,23 @@ impl History {
     }
 }
 
-impl From<&str> for History {
-    fn from(input: &str) -> Self {
-        Self {
-            values: input
-                .trim()
-                .split(' ')
-                .map(|part| part.parse().unwrap())
-                .collect()
+impl TryFrom<&str> for History {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let values: Vec<i64> = parse::numbers(input, input)?;
+
+        if values.is_empty() {
+            return Err(ParseError::at(input, input, "expected at least one value"));
         }
+
+        Ok(History::new(values))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc::ParseError;
     use indoc::indoc;
 
     use super::{History, Report}; 
@@ -139,6 +148,10 @@ mod tests {
             ],
         };
 
-        assert_eq!(Report::from(input), expected);
+        assert_eq!(Report::try_from(input), Ok(expected));
+
+        let input = "0 3 6\n1 3 x 10\n";
+
+        assert_eq!(Report::try_from(input), Err(ParseError::new(2, 5, "x", "expected a number")));
     }
 }
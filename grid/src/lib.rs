@@ -0,0 +1,223 @@
+//! Two dimensional grids of cells read from the puzzle input
+
+mod position;
+pub mod render;
+
+use std::ops::{Index, IndexMut};
+
+use aoc::ParseError;
+
+pub use position::Position;
+
+/// Rectangular grid of cells stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from cells given row by row
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells is not a multiple of the width.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            cells.len().is_multiple_of(width),
+            "Cells do not fill rows of width {}",
+            width,
+        );
+
+        Self { cells, width }
+    }
+
+    /// Parse a grid with one row per line, converting every character with `cell`
+    ///
+    /// Lines are trimmed and blank lines are skipped. Every row must have the same number of
+    /// cells, and the error returned by `cell` is reported at the character it was given.
+    pub fn parse<F, E>(input: &str, cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Into<String>,
+    {
+        let (cells, width) = aoc::parse::grid(input, input, cell)?;
+
+        Ok(Self::from_cells(width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.height() && position.col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            self.cells.get(position.row * self.width + position.col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            self.cells.get_mut(position.row * self.width + position.col)
+        } else {
+            None
+        }
+    }
+
+    /// Cells of each row, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every position of the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |n| Position::new(n / width, n % width))
+    }
+
+    /// Every cell of the grid along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Neighbours of a position sharing an edge with it that are within the grid
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        position
+            .neighbours4()
+            .filter(|&p| self.contains(p))
+    }
+
+    /// Neighbours of a position sharing an edge or a corner with it that are within the grid
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        position
+            .neighbours8()
+            .filter(|&p| self.contains(p))
+    }
+
+    /// Grid of the same shape with every cell converted by `f`
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+        }
+    }
+}
+
+impl TryFrom<&str> for Grid<char> {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Grid::parse(input, Ok::<char, String>)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("Position {:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("Position {:?} is outside of the grid", position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::ParseError;
+    use indoc::indoc;
+
+    use super::{Grid, Position};
+
+    static INPUT: &str = indoc!{"
+        #..
+        .#.
+        ..#
+        .##
+    "};
+
+    fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+        Grid::parse(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected '#' or '.'"),
+        })
+    }
+
+    #[test]
+    fn parsing() {
+        let grid = parse(INPUT).unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 4);
+        assert!(grid[Position::new(3, 2)]);
+        assert!(!grid[Position::new(3, 0)]);
+        assert_eq!(grid.get(Position::new(4, 0)), None);
+        assert_eq!(grid.get(Position::new(0, 3)), None);
+
+        assert_eq!(
+            parse("#..\n.x.\n"),
+            Err(ParseError::new(2, 2, "x", "expected '#' or '.'")),
+        );
+        assert_eq!(
+            parse("#..\n.#\n"),
+            Err(ParseError::new(2, 1, ".#", "expected a row of 3 cells, found 2")),
+        );
+    }
+
+    #[test]
+    fn iteration() {
+        let grid = parse(INPUT).unwrap();
+        let filled: Vec<Position> = grid
+            .iter()
+            .filter(|(_, &filled)| filled)
+            .map(|(p, _)| p)
+            .collect();
+
+        assert_eq!(filled, vec![
+            Position::new(0, 0),
+            Position::new(1, 1),
+            Position::new(2, 2),
+            Position::new(3, 1),
+            Position::new(3, 2),
+        ]);
+
+        assert_eq!(grid.rows().nth(3), Some([false, true, true].as_slice()));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::try_from(INPUT).unwrap();
+
+        assert_eq!(grid.neighbours4(Position::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Position::new(3, 2)).count(), 3);
+        assert_eq!(grid.neighbours8(Position::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn indexing() {
+        let mut grid = Grid::from_cells(2, vec![1, 2, 3, 4]);
+        grid[Position::new(1, 0)] = 5;
+
+        assert_eq!(grid.map(|n| n * 2), Grid::from_cells(2, vec![2, 4, 10, 8]));
+    }
+}
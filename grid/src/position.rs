@@ -0,0 +1,97 @@
+/// Location of a cell in a grid, rows grow downwards and columns grow to the right
+///
+/// Positions are ordered row by row, which is the order the cells of a grid are read in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    pub fn up(&self) -> Option<Self> {
+        Some(Self::new(self.row.checked_sub(1)?, self.col))
+    }
+
+    pub fn down(&self) -> Option<Self> {
+        Some(Self::new(self.row.checked_add(1)?, self.col))
+    }
+
+    pub fn left(&self) -> Option<Self> {
+        Some(Self::new(self.row, self.col.checked_sub(1)?))
+    }
+
+    pub fn right(&self) -> Option<Self> {
+        Some(Self::new(self.row, self.col.checked_add(1)?))
+    }
+
+    /// Positions sharing an edge with this one, in the order up, right, down, left
+    ///
+    /// Only positions that would fall outside of `usize` are skipped, use
+    /// [`Grid::neighbours4`](crate::Grid::neighbours4) to also stay within a grid.
+    pub fn neighbours4(&self) -> impl Iterator<Item = Position> {
+        [self.up(), self.right(), self.down(), self.left()]
+            .into_iter()
+            .flatten()
+    }
+
+    /// Positions sharing an edge or a corner with this one, clockwise starting from the top left
+    pub fn neighbours8(&self) -> impl Iterator<Item = Position> {
+        let up = self.up();
+        let down = self.down();
+
+        [
+            up.and_then(|p| p.left()),
+            up,
+            up.and_then(|p| p.right()),
+            self.right(),
+            down.and_then(|p| p.right()),
+            down,
+            down.and_then(|p| p.left()),
+            self.left(),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Number of steps between two positions when moving along rows and columns
+    pub fn manhattan(&self, other: &Position) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::Position;
+
+    #[test]
+    fn neighbours() {
+        let p = Position::new(1, 1);
+
+        assert_eq!(p.neighbours4().count(), 4);
+        assert_eq!(p.neighbours8().count(), 8);
+        assert!(p.neighbours8().collect::<HashSet<_>>().is_superset(&p.neighbours4().collect()));
+
+        let corner = Position::new(0, 0);
+
+        assert_eq!(
+            corner.neighbours4().collect::<Vec<_>>(),
+            vec![Position::new(0, 1), Position::new(1, 0)],
+        );
+        assert_eq!(
+            corner.neighbours8().collect::<Vec<_>>(),
+            vec![Position::new(0, 1), Position::new(1, 1), Position::new(1, 0)],
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Position::new(0, 9) < Position::new(1, 0));
+        assert_eq!(Position::new(2, 7).manhattan(&Position::new(5, 1)), 9);
+    }
+}
@@ -0,0 +1,292 @@
+//! Pictures of character grids with highlighted cells, for a terminal or as SVG
+//!
+//! A [`Picture`] is a grid of characters along with layers of positions, each drawn in its own
+//! color. Layers added later are drawn over the ones before them, so a cell in several layers
+//! takes the color of the last one.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::{Grid, Position};
+
+/// Width of a cell in an SVG picture
+const CELL_WIDTH: usize = 10;
+/// Height of a cell, and of a line of text, in an SVG picture
+const CELL_HEIGHT: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    /// Code of the foreground color in an ANSI escape sequence
+    fn ansi(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+
+    fn svg(self) -> &'static str {
+        match self {
+            Color::Red => "#e06c75",
+            Color::Green => "#98c379",
+            Color::Yellow => "#e5c07b",
+            Color::Blue => "#61afef",
+            Color::Magenta => "#c678dd",
+            Color::Cyan => "#56b6c2",
+        }
+    }
+}
+
+/// Positions highlighted with the same color
+#[derive(Debug, Clone)]
+struct Layer {
+    name: String,
+    color: Color,
+    positions: HashSet<Position>,
+}
+
+/// Grid of characters with highlighted cells
+#[derive(Debug, Clone)]
+pub struct Picture {
+    title: String,
+    grid: Grid<char>,
+    layers: Vec<Layer>,
+}
+
+impl Picture {
+    pub fn new(title: impl Into<String>, grid: Grid<char>) -> Self {
+        Self { title: title.into(), grid, layers: Vec::new() }
+    }
+
+    /// Highlight `positions` in `color` over the layers added before, positions outside of the grid
+    /// are ignored
+    pub fn with_layer<I>(mut self, name: impl Into<String>, color: Color, positions: I) -> Self
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        let positions = positions
+            .into_iter()
+            .filter(|&p| self.grid.contains(p))
+            .collect();
+
+        self.layers.push(Layer { name: name.into(), color, positions });
+        self
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Color of a cell, from the last layer that contains it
+    pub fn color(&self, position: Position) -> Option<Color> {
+        self.layers
+            .iter()
+            .rev()
+            .find(|layer| layer.positions.contains(&position))
+            .map(|layer| layer.color)
+    }
+
+    /// Cells of each row grouped in runs of the same color
+    fn runs(&self) -> impl Iterator<Item = Vec<(Option<Color>, String)>> + '_ {
+        self.grid.rows().enumerate().map(|(row, cells)| {
+            let mut runs: Vec<(Option<Color>, String)> = Vec::new();
+
+            for (col, &c) in cells.iter().enumerate() {
+                let color = self.color(Position::new(row, col));
+
+                match runs.last_mut() {
+                    Some((last, text)) if *last == color => text.push(c),
+                    _ => runs.push((color, c.to_string())),
+                }
+            }
+
+            runs
+        })
+    }
+
+    /// Title, rows colored with ANSI escape sequences, and a legend with the size of each layer
+    pub fn ansi(&self) -> String {
+        let mut out = format!("\x1b[1m{}\x1b[0m\n", self.title);
+
+        for runs in self.runs() {
+            for (color, text) in runs {
+                match color {
+                    Some(color) => write!(out, "\x1b[{}m{}\x1b[0m", color.ansi(), text).unwrap(),
+                    None => out.push_str(&text),
+                }
+            }
+
+            out.push('\n');
+        }
+
+        for layer in &self.layers {
+            writeln!(out, "\x1b[{}m■\x1b[0m {} ({})", layer.color.ansi(), layer.name, layer.positions.len()).unwrap();
+        }
+
+        out
+    }
+
+    /// Standalone SVG document with the title, the grid and a legend
+    ///
+    /// Highlighted cells are drawn as colored squares behind the characters, and every row is a
+    /// single line of monospace text stretched to the width of its cells.
+    pub fn svg(&self) -> String {
+        let width = (self.grid.width() * CELL_WIDTH).max(200) + 2 * CELL_WIDTH;
+        let top = 2 * CELL_HEIGHT;
+        let legend = top + (self.grid.height() + 1) * CELL_HEIGHT;
+        let height = legend + (self.layers.len() + 1) * CELL_HEIGHT;
+
+        let mut out = String::new();
+
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#,
+        ).unwrap();
+        writeln!(out, r##"<rect width="100%" height="100%" fill="#282c34"/>"##).unwrap();
+        writeln!(
+            out,
+            r##"<text x="{}" y="{}" font-family="sans-serif" font-size="14" fill="#abb2bf">{}</text>"##,
+            CELL_WIDTH,
+            CELL_HEIGHT + 4,
+            escape(&self.title),
+        ).unwrap();
+
+        for (p, _) in self.grid.iter() {
+            if let Some(color) = self.color(p) {
+                writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" fill-opacity="0.6"/>"#,
+                    CELL_WIDTH + p.col * CELL_WIDTH,
+                    top + p.row * CELL_HEIGHT,
+                    CELL_WIDTH,
+                    CELL_HEIGHT,
+                    color.svg(),
+                ).unwrap();
+            }
+        }
+
+        writeln!(out, r##"<g font-family="monospace" font-size="14" fill="#abb2bf" xml:space="preserve">"##).unwrap();
+
+        for (row, cells) in self.grid.rows().enumerate() {
+            let text: String = cells.iter().collect();
+
+            writeln!(
+                out,
+                r#"<text x="{}" y="{}" textLength="{}" lengthAdjust="spacing">{}</text>"#,
+                CELL_WIDTH,
+                top + row * CELL_HEIGHT + CELL_HEIGHT - 4,
+                cells.len() * CELL_WIDTH,
+                escape(&text),
+            ).unwrap();
+        }
+
+        writeln!(out, "</g>").unwrap();
+
+        for (n, layer) in self.layers.iter().enumerate() {
+            let y = legend + n * CELL_HEIGHT;
+
+            writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                CELL_WIDTH,
+                y + 3,
+                CELL_WIDTH,
+                CELL_WIDTH,
+                layer.color.svg(),
+            ).unwrap();
+            writeln!(
+                out,
+                r##"<text x="{}" y="{}" font-family="sans-serif" font-size="12" fill="#abb2bf">{} ({})</text>"##,
+                3 * CELL_WIDTH,
+                y + CELL_HEIGHT - 3,
+                escape(&layer.name),
+                layer.positions.len(),
+            ).unwrap();
+        }
+
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+/// Replace the characters that cannot appear as they are in the text of an XML element
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, Position};
+
+    use super::{Color, Picture};
+
+    fn picture() -> Picture {
+        let grid = Grid::try_from("a<b\n&.c\n").unwrap();
+
+        Picture::new("Test <1>", grid)
+            .with_layer("first", Color::Red, [Position::new(0, 0), Position::new(0, 1), Position::new(1, 2)])
+            .with_layer("second", Color::Blue, [Position::new(1, 2), Position::new(5, 5)])
+    }
+
+    #[test]
+    fn layers() {
+        let picture = picture();
+
+        assert_eq!(picture.color(Position::new(0, 0)), Some(Color::Red));
+        assert_eq!(picture.color(Position::new(1, 2)), Some(Color::Blue));
+        assert_eq!(picture.color(Position::new(1, 0)), None);
+    }
+
+    #[test]
+    fn ansi() {
+        let expected = concat!(
+            "\x1b[1mTest <1>\x1b[0m\n",
+            "\x1b[31ma<\x1b[0mb\n",
+            "&.\x1b[34mc\x1b[0m\n",
+            "\x1b[31m■\x1b[0m first (3)\n",
+            "\x1b[34m■\x1b[0m second (1)\n",
+        );
+
+        assert_eq!(picture().ansi(), expected);
+    }
+
+    #[test]
+    fn svg() {
+        let svg = picture().svg();
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains(">Test &lt;1&gt;</text>"));
+        assert!(svg.contains(">a&lt;b</text>"));
+        assert!(svg.contains(">&amp;.c</text>"));
+        assert!(svg.contains(">second (1)</text>"));
+        assert_eq!(svg.matches("fill-opacity").count(), 3);
+    }
+}
@@ -0,0 +1,190 @@
+//! Benchmark of every day, run with `cargo bench -p runner` or `cargo bench -p runner -- DAY...`
+//!
+//! Each day is solved repeatedly and the median time of parsing and of each part is reported.
+//! The medians are recorded in `target/bench-history.csv` along with the commit they were
+//! measured on, and compared with the most recent results of another commit.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use aoc::{Answer, Day, Solved};
+use runner::answers;
+use runner::timing::{format_duration, summary};
+
+/// Time spent running each day, at least `MIN_RUNS` runs are made even if it is exceeded
+const BUDGET: Duration = Duration::from_secs(2);
+const MIN_RUNS: usize = 3;
+const MAX_RUNS: usize = 100;
+
+const HISTORY_FILE: &str = "bench-history.csv";
+const HISTORY_HEADER: &str = "commit,day,phase,nanos";
+
+fn median(mut times: Vec<Duration>) -> Duration {
+    times.sort();
+    times[times.len() / 2]
+}
+
+/// Solve a day repeatedly, returning the median time of parsing and of each part
+fn bench(day: &Day, input: &str) -> Solved {
+    let start = Instant::now();
+    let mut runs: Vec<Solved> = Vec::new();
+
+    while runs.len() < MIN_RUNS || (start.elapsed() < BUDGET && runs.len() < MAX_RUNS) {
+        let solved = (day.solve)(input, None)
+            .unwrap_or_else(|err| panic!("Could not parse input for day {}: {}", day.number, err));
+
+        runs.push(solved);
+    }
+
+    let answers = runs[0]
+        .answers
+        .iter()
+        .map(|answer| Answer {
+            part: answer.part,
+            value: answer.value.clone(),
+            type_name: answer.type_name,
+            time: median(runs.iter().filter_map(|run| run.answer(answer.part)).map(|a| a.time).collect()),
+        })
+        .collect();
+
+    Solved {
+        parse_time: median(runs.iter().map(|run| run.parse_time).collect()),
+        answers,
+    }
+}
+
+/// Short hash of the checked out commit, marked as dirty if the tree has uncommitted changes
+fn commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty", "--exclude", "*"])
+        .current_dir(answers::workspace_root())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn history_path() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| answers::workspace_root().join("target"))
+        .join(HISTORY_FILE)
+}
+
+/// Rows of the history: commit, day, phase and median time in nanoseconds
+fn read_history() -> Vec<(String, u32, String, u128)> {
+    let text = fs::read_to_string(history_path()).unwrap_or_default();
+
+    text.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split(',');
+            let commit = fields.next()?.to_string();
+            let day = fields.next()?.parse().ok()?;
+            let phase = fields.next()?.to_string();
+            let nanos = fields.next()?.parse().ok()?;
+
+            Some((commit, day, phase, nanos))
+        })
+        .collect()
+}
+
+fn phases(solved: &Solved) -> Vec<(String, Duration)> {
+    let mut phases = vec![("parse".to_string(), solved.parse_time)];
+
+    for answer in &solved.answers {
+        phases.push((format!("part{}", answer.part.number()), answer.time));
+    }
+
+    phases
+}
+
+fn main() {
+    let selected: Vec<u32> = env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .map(|arg| arg.parse().unwrap_or_else(|_| panic!("Not a day: {}", arg)))
+        .collect();
+
+    let mut results = Vec::new();
+
+    for day in runner::DAYS.iter().filter(|day| selected.is_empty() || selected.contains(&day.number)) {
+        let path = answers::workspace_root().join(aoc::input::file_name(day.number));
+        let input = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Could not read {}: {}", path.display(), err));
+
+        eprintln!("Benchmarking day {}", day.number);
+        results.push((day.number, bench(day, &input)));
+    }
+
+    print!("{}", summary(&results));
+
+    let commit = commit();
+    let history = read_history();
+    let previous = history
+        .iter()
+        .rev()
+        .map(|(c, ..)| c)
+        .find(|&c| *c != commit);
+
+    if let Some(previous) = previous {
+        let before: HashMap<(u32, &str), u128> = history
+            .iter()
+            .filter(|(c, ..)| c == previous)
+            .map(|(_, day, phase, nanos)| ((*day, phase.as_str()), *nanos))
+            .collect();
+
+        println!("\nCompared with {}:", previous);
+
+        for (day, solved) in &results {
+            for (phase, time) in phases(solved) {
+                let Some(&nanos) = before.get(&(*day, phase.as_str())) else {
+                    continue;
+                };
+
+                let change = (time.as_nanos() as f64 / nanos as f64 - 1.0) * 100.0;
+                let before = format_duration(Duration::from_nanos(nanos as u64));
+
+                println!(
+                    "Day {:<3} {:<6} {:>10} -> {:>10} {:>+7.1}%",
+                    day,
+                    phase,
+                    before,
+                    format_duration(time),
+                    change,
+                );
+            }
+        }
+    }
+
+    let benched: Vec<u32> = results.iter().map(|(day, _)| *day).collect();
+    let mut lines = vec![HISTORY_HEADER.to_string()];
+
+    for (c, day, phase, nanos) in &history {
+        if *c != commit || !benched.contains(day) {
+            lines.push(format!("{},{},{},{}", c, day, phase, nanos));
+        }
+    }
+
+    for (day, solved) in &results {
+        for (phase, time) in phases(solved) {
+            lines.push(format!("{},{},{},{}", commit, day, phase, time.as_nanos()));
+        }
+    }
+
+    let path = history_path();
+
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    match fs::write(&path, lines.join("\n") + "\n") {
+        Ok(()) => println!("\nResults recorded in {} for {}", path.display(), commit),
+        Err(err) => eprintln!("Could not write {}: {}", path.display(), err),
+    }
+}
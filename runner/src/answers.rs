@@ -0,0 +1,82 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc::Part;
+use serde::Deserialize;
+
+/// Name of the file recording the answers, at the root of the workspace
+pub const FILE_NAME: &str = "answers.toml";
+
+#[derive(Debug, Deserialize)]
+struct Answers {
+    answer: Vec<Answer>,
+}
+
+/// Answer expected for one part of a day with the committed puzzle input
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Answer {
+    pub day: u32,
+    pub part: u32,
+    pub expected: String,
+}
+
+impl Answer {
+    pub fn is(&self, day: u32, part: Part) -> bool {
+        self.day == day && self.part == part.number()
+    }
+}
+
+/// Root of the workspace this crate was built from
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Runner crate is not inside the workspace")
+        .to_path_buf()
+}
+
+pub fn parse(text: &str) -> Result<Vec<Answer>, toml::de::Error> {
+    toml::from_str::<Answers>(text).map(|answers| answers.answer)
+}
+
+/// Read the answers recorded in the workspace
+pub fn read() -> io::Result<Vec<Answer>> {
+    let path = workspace_root().join(FILE_NAME);
+    let text = fs::read_to_string(&path)
+        .map_err(|err| io::Error::new(err.kind(), format!("Could not read {}: {}", path.display(), err)))?;
+
+    parse(&text).map_err(|err| {
+        io::Error::new(io::ErrorKind::InvalidData, format!("Could not parse {}: {}", path.display(), err))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use super::{Answer, parse};
+
+    #[test]
+    fn parsing() {
+        let text = r#"
+            [[answer]]
+            day = 5
+            part = 1
+            expected = "35"
+
+            [[answer]]
+            day = 5
+            part = 2
+            expected = "46"
+        "#;
+
+        let answers = parse(text).unwrap();
+
+        assert_eq!(answers, vec![
+            Answer { day: 5, part: 1, expected: "35".to_string() },
+            Answer { day: 5, part: 2, expected: "46".to_string() },
+        ]);
+        assert!(answers[1].is(5, Part::Two));
+        assert!(parse("[[answer]]\nday = 1\n").is_err());
+    }
+}
@@ -0,0 +1,42 @@
+pub mod answers;
+pub mod render;
+pub mod report;
+pub mod scaffold;
+pub mod timing;
+
+use aoc::Day;
+
+/// Every solved day of the advent calendar, in order
+pub static DAYS: [Day; 12] = [
+    Day::streaming::<day1::Day1>(),
+    Day::streaming::<day2::Day2>(),
+    Day::new::<day3::Day3>(),
+    Day::streaming::<day4::Day4>(),
+    Day::new::<day5::Day5>(),
+    Day::new::<day6::Day6>(),
+    Day::streaming::<day7::Day7>(),
+    Day::new::<day8::Day8>(),
+    Day::streaming::<day9::Day9>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::streaming::<day12::Day12>(),
+];
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DAYS, find};
+
+    #[test]
+    fn days_in_order() {
+        for (n, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.number as usize, n + 1);
+        }
+
+        assert!(find(12).is_some());
+        assert!(find(25).is_none());
+    }
+}
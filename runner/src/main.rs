@@ -0,0 +1,400 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc::client::{Client, HttpsTransport, Verdict};
+use aoc::input::Source;
+use aoc::stream::ReadError;
+use aoc::{Day, Part, Solved};
+use clap::{Parser, Subcommand, ValueEnum};
+use day1::calibration::Lenient;
+use day1::scanner::Vocabulary;
+
+/// Environment variable holding the session cookie of the website
+const SESSION_VAR: &str = "AOC_SESSION";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Answers of each day as lines of text
+    Text,
+    /// A JSON array with one object per day and part, holding its answer or error
+    Json,
+}
+
+/// Run the Advent of Code 2023 solutions
+#[derive(Debug, Parser)]
+#[command(name = "aoc", args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Day to run, or every day if omitted
+    day: Option<u32>,
+
+    /// Part to run, or both parts if omitted
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+
+    /// Puzzle input to use instead of inputs/dayN.txt, or - for stdin
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Print how long parsing and each part took once every day has run
+    #[arg(short, long)]
+    time: bool,
+
+    /// How the answers are printed
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Read the input one line at a time instead of all at once, for the days that support it
+    #[arg(long)]
+    stream: bool,
+
+    /// Number of threads solving the records of a day, one per core by default
+    #[cfg(feature = "parallel")]
+    #[arg(long)]
+    threads: Option<usize>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Download the input of a day to inputs/dayN.txt, with the session cookie in AOC_SESSION
+    Fetch {
+        day: u32,
+    },
+
+    /// Solve a part of a day and submit its answer, with the session cookie in AOC_SESSION
+    Submit {
+        day: u32,
+
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+
+        /// Puzzle input to use instead of inputs/dayN.txt, or - for stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+
+    /// Print a random input for a day, the same seed and size always give the same input
+    Generate {
+        day: u32,
+
+        /// Size of the input, such as its number of lines, which depends on the day
+        #[arg(short, long, default_value_t = 10)]
+        size: usize,
+
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+
+    /// Draw what the solution of day 3, 10 or 11 finds in its input, in color or as SVG files
+    Render {
+        day: u32,
+
+        /// Puzzle input to use instead of inputs/dayN.txt, or - for stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+
+        /// Directory to write dayN-<title>.svg files to instead of printing to the terminal
+        #[arg(long)]
+        svg: Option<PathBuf>,
+    },
+
+    /// Sum the calibration values of a day 1 input, finding digits with a vocabulary of words
+    Calibrate {
+        /// Puzzle input to use instead of inputs/day1.txt, or - for stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+
+        /// File with a `word = value` entry per line, digits and English words by default
+        #[arg(long)]
+        vocabulary: Option<PathBuf>,
+
+        /// Match words whatever the case of their letters, in any alphabet
+        #[arg(long)]
+        ignore_case: bool,
+
+        /// Skip the lines without a digit and count them, instead of failing at the first one
+        #[arg(long)]
+        lenient: bool,
+
+        /// Print every line with the words found in it, highlighting the first and last ones
+        #[arg(long)]
+        annotate: bool,
+    },
+
+    /// Create the crate of a new day from the template and add it to the workspace
+    New {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+
+        /// Title of the puzzle, for the documentation of the crate
+        #[arg(short, long, default_value = runner::scaffold::DEFAULT_TITLE)]
+        title: String,
+    },
+}
+
+fn run(day: &Day, part: Option<Part>, path: Option<&PathBuf>) -> Result<Solved, String> {
+    let input = aoc::input::read(day.number, path.map(PathBuf::as_path))
+        .map_err(|err| err.to_string())?;
+
+    (day.solve)(&input, part)
+        .map_err(|err| format!("Could not parse input for day {}: {}", day.number, err))
+}
+
+/// Like [`run`], but for days that can be solved reading their input one line at a time
+fn run_stream(day: &Day, part: Option<Part>, path: Option<&PathBuf>) -> Result<Solved, String> {
+    let Some(stream) = day.stream else {
+        return run(day, part, path);
+    };
+
+    let mut reader = Source::resolve(day.number, path.map(PathBuf::as_path))
+        .and_then(|source| source.reader())
+        .map_err(|err| err.to_string())?;
+
+    stream(&mut reader, part).map_err(|err| match err {
+        ReadError::Io(err) => err.to_string(),
+        ReadError::Parse(err) => format!("Could not parse input for day {}: {}", day.number, err),
+    })
+}
+
+/// Client for the website, storing inputs under the closest directory that has an `inputs` one
+fn client() -> Result<Client<HttpsTransport>, String> {
+    let session = env::var(SESSION_VAR)
+        .map_err(|_| format!("Set {} to the session cookie of the website", SESSION_VAR))?;
+
+    let cwd = env::current_dir().map_err(|err| err.to_string())?;
+    let root = cwd
+        .ancestors()
+        .find(|dir| dir.join(aoc::input::INPUT_DIR).is_dir())
+        .unwrap_or(&cwd);
+
+    Ok(Client::new(HttpsTransport, session.trim()).with_root(root))
+}
+
+fn fetch(day: u32) -> Result<(), String> {
+    let client = client()?;
+
+    client
+        .input(day)
+        .map_err(|err| format!("Could not download input for day {}: {}", day, err))?;
+
+    println!("Input for day {} saved in {}", day, client.cache_path(day).display());
+    Ok(())
+}
+
+fn submit(number: u32, part: Part, path: Option<&PathBuf>) -> Result<(), String> {
+    let day = runner::find(number).ok_or_else(|| format!("Day {} has not been solved", number))?;
+    let client = client()?;
+    let solved = run(day, Some(part), path)?;
+    let answer = solved.answer(part).expect("The requested part is solved");
+
+    if answer.value == aoc::Unsolved.to_string() {
+        return Err(format!("Day {} {} has not been solved", number, part));
+    }
+
+    let verdict = client
+        .submit(number, part, &answer.value)
+        .map_err(|err| format!("Could not submit answer for day {}: {}", number, err))?;
+
+    println!("Day {} {}: {} is {}", number, part, answer.value, verdict);
+
+    if verdict == Verdict::Correct {
+        Ok(())
+    } else {
+        Err(format!("Answer was not accepted: {}", verdict))
+    }
+}
+
+fn generate(number: u32, seed: u64, size: usize) -> Result<(), String> {
+    let day = runner::find(number).ok_or_else(|| format!("Day {} has not been solved", number))?;
+
+    print!("{}", (day.generate)(seed, size));
+    Ok(())
+}
+
+fn render(number: u32, path: Option<&PathBuf>, svg: Option<&PathBuf>) -> Result<(), String> {
+    let input = aoc::input::read(number, path.map(PathBuf::as_path)).map_err(|err| err.to_string())?;
+    let pictures = runner::render::pictures(number, &input)
+        .ok_or_else(|| format!("Day {} cannot be drawn, only days {:?} can", number, runner::render::DAYS))?
+        .map_err(|err| format!("Could not parse input for day {}: {}", number, err))?;
+
+    for picture in pictures {
+        match svg {
+            Some(dir) => {
+                let path = dir.join(runner::render::file_name(number, &picture));
+
+                std::fs::write(&path, picture.svg())
+                    .map_err(|err| format!("Could not write {}: {}", path.display(), err))?;
+
+                println!("Wrote {}", path.display());
+            }
+            None => println!("{}", picture.ansi()),
+        }
+    }
+
+    Ok(())
+}
+
+fn calibrate(
+    path: Option<&PathBuf>,
+    vocabulary: Option<&PathBuf>,
+    ignore_case: bool,
+    lenient: bool,
+    annotate: bool,
+) -> Result<(), String> {
+    let input = aoc::input::read(1, path.map(PathBuf::as_path)).map_err(|err| err.to_string())?;
+
+    let vocabulary = match vocabulary {
+        Some(path) => {
+            let text = std::fs::read_to_string(path)
+                .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+
+            Vocabulary::try_from(text.as_str())
+                .map_err(|err| format!("Could not parse vocabulary {}: {}", path.display(), err))?
+        }
+        None => Vocabulary::default(),
+    };
+
+    let scanner = vocabulary.ignore_case(ignore_case).scanner();
+
+    if annotate {
+        let color = io::stdout().is_terminal();
+
+        for annotation in scanner.annotations(&input) {
+            println!("{}", annotation.render(color));
+        }
+    }
+
+    let report = scanner.sum(&input);
+
+    if lenient {
+        for err in report.invalid() {
+            eprintln!("Skipped {}", err);
+        }
+
+        let Lenient { sum, skipped } = report.lenient();
+        println!("{} ({} skipped)", sum, skipped);
+    } else {
+        let sum = report
+            .strict()
+            .map_err(|err| format!("Could not calibrate input: {}", err))?;
+
+        println!("{}", sum);
+    }
+
+    Ok(())
+}
+
+fn new(day: u32, title: &str) -> Result<(), String> {
+    let root = runner::answers::workspace_root();
+    let files = runner::scaffold::create(&root, day, title)
+        .map_err(|err| format!("Could not create day {}: {}", day, err))?;
+
+    for path in files {
+        println!("Wrote {}", path.strip_prefix(&root).unwrap_or(&path).display());
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    if let Some(command) = args.command {
+        let result = match command {
+            Command::Fetch { day } => fetch(day),
+            Command::Submit { day, part, input } => {
+                let part = Part::try_from(part).expect("Part is validated by the parser");
+                submit(day, part, input.as_ref())
+            }
+            Command::Generate { day, size, seed } => generate(day, seed, size),
+            Command::Render { day, input, svg } => render(day, input.as_ref(), svg.as_ref()),
+            Command::Calibrate { input, vocabulary, ignore_case, lenient, annotate } => {
+                calibrate(input.as_ref(), vocabulary.as_ref(), ignore_case, lenient, annotate)
+            }
+            Command::New { day, title } => new(day, &title),
+        };
+
+        return match result {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{}", err);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    #[cfg(feature = "parallel")]
+    if let Some(n) = args.threads {
+        if let Err(err) = aoc::parallel::set_threads(n) {
+            eprintln!("Could not start {} threads: {}", n, err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let part = args.part.map(|n| Part::try_from(n).expect("Part is validated by the parser"));
+
+    let days: Vec<&Day> = match args.day {
+        Some(number) => match runner::find(number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("Day {} has not been solved", number);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => runner::DAYS.iter().collect(),
+    };
+
+    let parts = match part {
+        Some(p) => vec![p],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut timings = Vec::new();
+    let mut records = Vec::new();
+
+    for day in days {
+        let result = if args.stream {
+            run_stream(day, part, args.input.as_ref())
+        } else {
+            run(day, part, args.input.as_ref())
+        };
+
+        if result.is_err() {
+            status = ExitCode::FAILURE;
+        }
+
+        match args.format {
+            Format::Json => records.extend(runner::report::records(day.number, &parts, &result)),
+            Format::Text => match &result {
+                Ok(solved) => {
+                    println!("Day {}", day.number);
+
+                    for answer in &solved.answers {
+                        println!("{}: {}", answer.part, answer.value);
+                    }
+                }
+                Err(err) => eprintln!("{}", err),
+            },
+        }
+
+        if let Ok(solved) = result {
+            timings.push((day.number, solved));
+        }
+    }
+
+    match args.format {
+        Format::Json => {
+            let json = serde_json::to_string_pretty(&records).expect("Records can be serialized");
+            println!("{}", json);
+        }
+        Format::Text if args.time => print!("\n{}", runner::timing::summary(&timings)),
+        Format::Text => {}
+    }
+
+    status
+}
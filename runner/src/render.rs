@@ -0,0 +1,61 @@
+use aoc::{ParseError, Solution};
+use grid::render::Picture;
+
+/// Days whose input is a grid that can be drawn
+pub const DAYS: [u32; 3] = [3, 10, 11];
+
+/// Pictures of what the solution of a day finds in its input, or `None` if the day has none
+pub fn pictures(day: u32, input: &str) -> Option<Result<Vec<Picture>, ParseError>> {
+    let pictures = match day {
+        3 => day3::Day3::parse(input).map(|schematic| vec![schematic.picture()]),
+        10 => day10::Day10::parse(input).map(|pipes| vec![pipes.picture()]),
+        11 => day11::Day11::parse(input).map(|galaxies| vec![
+            day11::picture("Galaxies", &galaxies),
+            day11::picture("Expanded galaxies", &day11::expand_galaxies(&galaxies, 1)),
+        ]),
+        _ => return None,
+    };
+
+    Some(pictures)
+}
+
+/// Name of the SVG file of a picture, such as `day11-expanded-galaxies.svg`
+pub fn file_name(day: u32, picture: &Picture) -> String {
+    let slug = picture
+        .title()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-");
+
+    format!("day{}-{}.svg", day, slug)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DAYS, file_name, pictures};
+
+    #[test]
+    fn pictured_days() {
+        for day in crate::DAYS.iter() {
+            let input = (day.generate)(0, 10);
+
+            match pictures(day.number, &input) {
+                Some(pictures) => {
+                    assert!(DAYS.contains(&day.number), "Day {}", day.number);
+
+                    for picture in pictures.unwrap() {
+                        assert!(file_name(day.number, &picture).starts_with(&format!("day{}-", day.number)));
+                    }
+                },
+                None => assert!(!DAYS.contains(&day.number), "Day {}", day.number),
+            }
+        }
+
+        let galaxies = pictures(11, "#.\n..\n.#\n").unwrap().unwrap();
+
+        assert_eq!(file_name(11, &galaxies[1]), "day11-expanded-galaxies.svg");
+        assert!(pictures(10, "S-x\n").unwrap().is_err());
+    }
+}
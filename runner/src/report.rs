@@ -0,0 +1,92 @@
+use aoc::{Part, Solved};
+use serde::Serialize;
+
+/// Outcome of running one part of a day, as printed by `--format json`
+///
+/// When the input could not be read or parsed, every requested part of the day gets a record
+/// holding the error and no answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub answer: Option<String>,
+    #[serde(rename = "type")]
+    pub type_name: Option<&'static str>,
+    pub parse_time_ns: Option<u64>,
+    pub time_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Records for the parts of a day, `parts` are the parts that were requested
+pub fn records(day: u32, parts: &[Part], result: &Result<Solved, String>) -> Vec<Record> {
+    match result {
+        Ok(solved) => solved
+            .answers
+            .iter()
+            .map(|answer| Record {
+                day,
+                part: answer.part.number(),
+                answer: Some(answer.value.clone()),
+                type_name: Some(answer.type_name),
+                parse_time_ns: Some(solved.parse_time.as_nanos() as u64),
+                time_ns: Some(answer.time.as_nanos() as u64),
+                error: None,
+            })
+            .collect(),
+        Err(err) => parts
+            .iter()
+            .map(|part| Record {
+                day,
+                part: part.number(),
+                answer: None,
+                type_name: None,
+                parse_time_ns: None,
+                time_ns: None,
+                error: Some(err.clone()),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc::{Answer, Part, Solved};
+
+    use super::records;
+
+    #[test]
+    fn solved() {
+        let solved = Solved {
+            parse_time: Duration::from_nanos(1_500),
+            answers: vec![Answer {
+                part: Part::Two,
+                value: "5905".to_string(),
+                type_name: "usize",
+                time: Duration::from_nanos(800),
+            }],
+        };
+
+        let json = serde_json::to_value(records(7, &[Part::Two], &Ok(solved))).unwrap();
+
+        assert_eq!(json, serde_json::json!([{
+            "day": 7,
+            "part": 2,
+            "answer": "5905",
+            "type": "usize",
+            "parse_time_ns": 1500,
+            "time_ns": 800,
+            "error": null,
+        }]));
+    }
+
+    #[test]
+    fn failed() {
+        let records = records(2, &Part::ALL, &Err("line 1, column 3: bad".to_string()));
+
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(|r| r.answer.is_none() && r.error.as_deref() == Some("line 1, column 3: bad")));
+        assert_eq!(records[1].part, 2);
+    }
+}
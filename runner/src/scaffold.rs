@@ -0,0 +1,363 @@
+//! Generate the crate of a new day from the templates in `runner/templates`
+//!
+//! Besides the crate itself, an empty input file is created, the crate is registered as a
+//! workspace member, as a dependency of the runner and in [`DAYS`](crate::DAYS), and both of its
+//! parts are recorded as unsolved in `answers.toml` until their answers are known.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const GENERATE_TEMPLATE: &str = include_str!("../templates/generate.rs.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+
+/// Title used when none is given, to be replaced by the title of the puzzle
+pub const DEFAULT_TITLE: &str = "TODO";
+
+fn render(template: &str, day: u32, title: &str) -> String {
+    template
+        .replace("{{day}}", &day.to_string())
+        .replace("{{title}}", title)
+}
+
+/// Insert `line` among the lines for which `day_of` finds a day, so that they stay in order
+///
+/// Returns `None` when no line has a day, or when one already has the day being inserted.
+fn insert_line<F>(text: &str, day: u32, line: &str, day_of: F) -> Option<String>
+where
+    F: Fn(&str) -> Option<u32>,
+{
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(n, l)| day_of(l).map(|d| (n, d)))
+        .collect();
+
+    if days.iter().any(|&(_, d)| d == day) {
+        return None;
+    }
+
+    let index = match days.iter().find(|&&(_, d)| d > day) {
+        Some(&(n, _)) => n,
+        None => days.last()?.0 + 1,
+    };
+
+    lines.insert(index, line);
+
+    let mut result = lines.join("\n");
+
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+
+    Some(result)
+}
+
+/// Add the crate of a day to the `members` of the workspace manifest
+pub fn add_member(manifest: &str, day: u32) -> Option<String> {
+    let line = format!("    \"day{}\",", day);
+
+    insert_line(manifest, day, &line, |l| {
+        l.trim().strip_prefix("\"day")?.strip_suffix("\",")?.parse().ok()
+    })
+}
+
+/// Add the crate of a day to the dependencies of the runner manifest
+pub fn add_dependency(manifest: &str, day: u32) -> Option<String> {
+    let line = format!("day{} = {{ path = \"../day{}\" }}", day, day);
+
+    insert_line(manifest, day, &line, |l| {
+        l.strip_prefix("day")?.split_once(" = ")?.0.parse().ok()
+    })
+}
+
+/// Add the solution of a day to `DAYS` in the source of the runner library
+pub fn add_day(source: &str, day: u32) -> Option<String> {
+    let line = format!("    Day::new::<day{}::Day{}>(),", day, day);
+    let source = insert_line(source, day, &line, |l| {
+        let (_, day) = l.trim().strip_prefix("Day::")?.split_once("::<day")?;
+        day.split_once("::")?.0.parse().ok()
+    })?;
+
+    let prefix = "pub static DAYS: [Day; ";
+    let start = source.find(prefix)? + prefix.len();
+    let end = start + source[start..].find(']')?;
+    let len: usize = source[start..end].parse().ok()?;
+
+    Some(format!("{}{}{}", &source[..start], len + 1, &source[end..]))
+}
+
+/// Add entries expecting both parts of a day to be unsolved to the recorded answers
+///
+/// Entries stay in the order of the days. Returns `None` when the day already has an entry.
+pub fn add_answers(answers: &str, day: u32) -> Option<String> {
+    let day_of = |block: &str| block.lines().find_map(|l| l.strip_prefix("day = ")?.parse::<u32>().ok());
+    let mut blocks: Vec<String> = answers.trim_end().split("\n\n").map(str::to_string).collect();
+
+    if blocks.iter().any(|block| day_of(block) == Some(day)) {
+        return None;
+    }
+
+    let index = blocks
+        .iter()
+        .position(|block| day_of(block).is_some_and(|d| d > day))
+        .unwrap_or(blocks.len());
+
+    let entries = [1, 2].map(|part| {
+        format!("[[answer]]\nday = {}\npart = {}\nexpected = \"{}\"", day, part, aoc::Unsolved)
+    });
+
+    blocks.splice(index..index, entries);
+
+    Some(blocks.join("\n\n") + "\n")
+}
+
+/// Update a file with `edit`, failing if it does not recognise the contents
+fn edit_file<F>(path: &Path, day: u32, edit: F) -> io::Result<(PathBuf, String)>
+where
+    F: Fn(&str, u32) -> Option<String>,
+{
+    let text = fs::read_to_string(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("Could not read {}: {}", path.display(), err)))?;
+
+    let edited = edit(&text, day).ok_or_else(|| {
+        let msg = format!("Could not add day {} to {}", day, path.display());
+        io::Error::new(io::ErrorKind::InvalidData, msg)
+    })?;
+
+    Ok((path.to_path_buf(), edited))
+}
+
+/// Create the `dayN` crate under the workspace `root` and register it, returning the files written
+///
+/// Nothing is written when the crate already exists or one of the files to update could not be
+/// edited. An existing input file is left untouched, and a new one is left empty, which `fetch`
+/// does not mistake for a downloaded input.
+pub fn create(root: &Path, day: u32, title: &str) -> io::Result<Vec<PathBuf>> {
+    let dir = root.join(format!("day{}", day));
+
+    if dir.exists() {
+        let msg = format!("{} already exists", dir.display());
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, msg));
+    }
+
+    let edits = [
+        edit_file(&root.join("Cargo.toml"), day, add_member)?,
+        edit_file(&root.join("runner").join("Cargo.toml"), day, add_dependency)?,
+        edit_file(&root.join("runner").join("src").join("lib.rs"), day, add_day)?,
+        edit_file(&root.join(crate::answers::FILE_NAME), day, add_answers)?,
+    ];
+
+    let mut files = vec![
+        (dir.join("Cargo.toml"), render(CARGO_TEMPLATE, day, title)),
+        (dir.join("src").join("generate.rs"), render(GENERATE_TEMPLATE, day, title)),
+        (dir.join("src").join("lib.rs"), render(LIB_TEMPLATE, day, title)),
+        (dir.join("src").join("main.rs"), render(MAIN_TEMPLATE, day, title)),
+    ];
+
+    let input = root.join(aoc::input::file_name(day));
+
+    if !input.exists() {
+        files.push((input, String::new()));
+    }
+
+    files.extend(edits);
+
+    for (path, text) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, text)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::ErrorKind;
+    use std::path::PathBuf;
+
+    use aoc::client::testing::mock_server;
+    use aoc::client::{Client, TcpTransport};
+    use indoc::indoc;
+
+    use crate::answers::workspace_root;
+
+    use super::{add_answers, add_day, add_dependency, add_member, create};
+
+    #[test]
+    fn members() {
+        let manifest = indoc! {r#"
+            [workspace]
+            members = [
+                "aoc",
+                "day1",
+                "day3",
+            ]
+        "#};
+
+        let expected = indoc! {r#"
+            [workspace]
+            members = [
+                "aoc",
+                "day1",
+                "day2",
+                "day3",
+            ]
+        "#};
+
+        assert_eq!(add_member(manifest, 2).as_deref(), Some(expected));
+        assert!(add_member(manifest, 4).unwrap().ends_with("    \"day3\",\n    \"day4\",\n]\n"));
+        assert_eq!(add_member(manifest, 3), None);
+    }
+
+    #[test]
+    fn dependencies() {
+        let manifest = indoc! {r#"
+            [dependencies]
+            aoc = { path = "../aoc" }
+            day9 = { path = "../day9" }
+            day10 = { path = "../day10" }
+            serde = "1.0"
+        "#};
+
+        let edited = add_dependency(manifest, 11).unwrap();
+
+        assert!(edited.contains("day10 = { path = \"../day10\" }\nday11 = { path = \"../day11\" }\nserde"));
+        assert_eq!(add_dependency("[dependencies]\n", 1), None);
+    }
+
+    #[test]
+    fn days() {
+        let source = indoc! {"
+            pub static DAYS: [Day; 2] = [
+                Day::new::<day1::Day1>(),
+                Day::streaming::<day3::Day3>(),
+            ];
+        "};
+
+        let expected = indoc! {"
+            pub static DAYS: [Day; 3] = [
+                Day::new::<day1::Day1>(),
+                Day::new::<day2::Day2>(),
+                Day::streaming::<day3::Day3>(),
+            ];
+        "};
+
+        assert_eq!(add_day(source, 2).as_deref(), Some(expected));
+        assert_eq!(add_day(source, 3), None);
+    }
+
+    #[test]
+    fn answers() {
+        let answers = indoc! {r#"
+            # Answers
+
+            [[answer]]
+            day = 1
+            part = 1
+            expected = "54990"
+
+            [[answer]]
+            day = 3
+            part = 1
+            expected = "539590"
+        "#};
+
+        let expected = indoc! {r#"
+            # Answers
+
+            [[answer]]
+            day = 1
+            part = 1
+            expected = "54990"
+
+            [[answer]]
+            day = 2
+            part = 1
+            expected = "unsolved"
+
+            [[answer]]
+            day = 2
+            part = 2
+            expected = "unsolved"
+
+            [[answer]]
+            day = 3
+            part = 1
+            expected = "539590"
+        "#};
+
+        assert_eq!(add_answers(answers, 2).as_deref(), Some(expected));
+        assert!(add_answers(answers, 4).unwrap().ends_with("day = 4\npart = 2\nexpected = \"unsolved\"\n"));
+        assert_eq!(add_answers(answers, 3), None);
+    }
+
+    #[test]
+    fn create_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let workspace = workspace_root();
+        let copies = ["Cargo.toml", "runner/Cargo.toml", "runner/src/lib.rs", "answers.toml"].map(PathBuf::from);
+
+        for path in &copies {
+            fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+            fs::copy(workspace.join(path), root.join(path)).unwrap();
+        }
+
+        let files = create(&root, 24, "Never Tell Me The Odds").unwrap();
+
+        assert_eq!(files.len(), 9);
+        assert_eq!(fs::read_to_string(root.join("inputs/day24.txt")).unwrap(), "");
+
+        let lib = fs::read_to_string(root.join("day24/src/lib.rs")).unwrap();
+        assert!(lib.starts_with("//! Day 24: Never Tell Me The Odds\n"));
+        assert!(lib.contains("impl Solution for Day24 {\n    const DAY: u32 = 24;"));
+
+        let generate = fs::read_to_string(root.join("day24/src/generate.rs")).unwrap();
+        assert!(generate.contains("impl Generate for Day24 {"));
+
+        let main = fs::read_to_string(root.join("day24/src/main.rs")).unwrap();
+        assert!(main.contains("aoc::main::<Day24>()"));
+
+        assert!(fs::read_to_string(root.join("Cargo.toml")).unwrap().contains("\"day24\","));
+        assert!(fs::read_to_string(root.join("runner/Cargo.toml")).unwrap().contains("day24 = { path = \"../day24\" }"));
+        assert!(fs::read_to_string(root.join("runner/src/lib.rs")).unwrap().contains("Day::new::<day24::Day24>(),"));
+        assert!(fs::read_to_string(root.join("answers.toml")).unwrap().contains("day = 24\npart = 2\nexpected = \"unsolved\""));
+
+        assert_eq!(create(&root, 24, "").unwrap_err().kind(), ErrorKind::AlreadyExists);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn fetch_after_create() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-fetch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let workspace = workspace_root();
+
+        for path in ["Cargo.toml", "runner/Cargo.toml", "runner/src/lib.rs", "answers.toml"] {
+            fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+            fs::copy(workspace.join(path), root.join(path)).unwrap();
+        }
+
+        create(&root, 23, "A Long Walk").unwrap();
+
+        let (url, requests) = mock_server(vec![(200, "#.#####\n")]);
+        let client = Client::new(TcpTransport, "secret")
+            .with_base_url(url)
+            .with_root(&root);
+
+        assert_eq!(client.input(23).unwrap(), "#.#####\n");
+        assert!(requests.recv().unwrap().starts_with("GET /2023/day/23/input HTTP/1.1\r\n"));
+        assert_eq!(fs::read_to_string(root.join("inputs/day23.txt")).unwrap(), "#.#####\n");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc::{Part, Solved};
+
+/// Format a duration with three significant digits in the most readable unit
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+
+    let precision = if value < 10.0 {
+        2
+    } else if value < 100.0 {
+        1
+    } else {
+        0
+    };
+
+    format!("{:.*}{}", precision, value, unit)
+}
+
+/// Table of the time spent parsing and solving each part of every day that was run
+///
+/// Parts that were not run are shown as `-`, and the last row holds the total for every column.
+pub fn summary(days: &[(u32, Solved)]) -> String {
+    let mut table = String::new();
+    let cell = |time: Option<Duration>| time.map_or_else(|| "-".to_string(), format_duration);
+    let part_time = |solved: &Solved, part| solved.answer(part).map(|answer| answer.time);
+
+    let _ = writeln!(table, "{:<5} {:>10} {:>10} {:>10} {:>10}", "Day", "Parse", "Part 1", "Part 2", "Total");
+
+    for (day, solved) in days {
+        let _ = writeln!(
+            table,
+            "{:<5} {:>10} {:>10} {:>10} {:>10}",
+            day,
+            cell(Some(solved.parse_time)),
+            cell(part_time(solved, Part::One)),
+            cell(part_time(solved, Part::Two)),
+            cell(Some(solved.total_time())),
+        );
+    }
+
+    let column_total = |time: &dyn Fn(&Solved) -> Option<Duration>| {
+        days.iter()
+            .filter_map(|(_, solved)| time(solved))
+            .reduce(|a, b| a + b)
+    };
+
+    let _ = writeln!(
+        table,
+        "{:<5} {:>10} {:>10} {:>10} {:>10}",
+        "Total",
+        cell(column_total(&|solved| Some(solved.parse_time))),
+        cell(column_total(&|solved| part_time(solved, Part::One))),
+        cell(column_total(&|solved| part_time(solved, Part::Two))),
+        cell(column_total(&|solved| Some(solved.total_time()))),
+    );
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc::{Answer, Part, Solved};
+
+    use super::{format_duration, summary};
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(950)), "950ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_234)), "1.23µs");
+        assert_eq!(format_duration(Duration::from_micros(45_670)), "45.7ms");
+        assert_eq!(format_duration(Duration::from_millis(6_600)), "6.60s");
+    }
+
+    #[test]
+    fn table() {
+        let answer = |part, micros| Answer {
+            part,
+            value: String::new(),
+            type_name: "usize",
+            time: Duration::from_micros(micros),
+        };
+
+        let days = [
+            (1, Solved {
+                parse_time: Duration::from_micros(10),
+                answers: vec![answer(Part::One, 20), answer(Part::Two, 30)],
+            }),
+            (5, Solved {
+                parse_time: Duration::from_micros(100),
+                answers: vec![answer(Part::One, 200)],
+            }),
+        ];
+
+        let expected = [
+            "Day        Parse     Part 1     Part 2      Total",
+            "1         10.0µs     20.0µs     30.0µs     60.0µs",
+            "5          100µs      200µs          -      300µs",
+            "Total      110µs      220µs     30.0µs      360µs",
+        ];
+
+        assert_eq!(summary(&days), expected.join("\n") + "\n");
+    }
+}
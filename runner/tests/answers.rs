@@ -0,0 +1,87 @@
+//! Regression tests checking every day against the answers recorded in `answers.toml`
+
+use std::fs;
+
+use aoc::Part;
+use runner::answers::{self, Answer};
+
+fn answers() -> Vec<Answer> {
+    answers::read().unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Solve every recorded answer, returning a description of each mismatch
+///
+/// Days are only parsed once, both parts are solved together when both are recorded.
+fn check() -> Vec<String> {
+    let root = answers::workspace_root();
+    let selected: Vec<Answer> = answers();
+    let mut failures = Vec::new();
+
+    for answer in &selected {
+        assert!(runner::find(answer.day).is_some(), "Day {} has not been solved", answer.day);
+    }
+
+    for day in &runner::DAYS {
+        let expected: Vec<&Answer> = selected.iter().filter(|a| a.day == day.number).collect();
+        let part = match expected.as_slice() {
+            [] => continue,
+            [answer] => Some(
+                Part::try_from(answer.part)
+                    .unwrap_or_else(|n| panic!("Day {} has no part {}", answer.day, n)),
+            ),
+            _ => None,
+        };
+
+        let path = root.join(aoc::input::file_name(day.number));
+        let input = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Could not read {}: {}", path.display(), err));
+
+        let solved = match (day.solve)(&input, part) {
+            Ok(solved) => solved,
+            Err(err) => {
+                failures.push(format!("Day {}: could not parse input: {}", day.number, err));
+                continue;
+            }
+        };
+
+        for answer in expected {
+            let actual = solved
+                .answers
+                .iter()
+                .find(|a| a.part.number() == answer.part)
+                .unwrap_or_else(|| panic!("Day {} has no part {}", answer.day, answer.part));
+
+            if actual.value != answer.expected {
+                failures.push(format!(
+                    "Day {} {}: expected {}, got {}",
+                    answer.day,
+                    actual.part,
+                    answer.expected,
+                    actual.value,
+                ));
+            }
+        }
+    }
+
+    failures
+}
+
+#[test]
+fn recorded_answers() {
+    let failures = check();
+
+    assert!(failures.is_empty(), "Answers changed:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn every_day_has_answers() {
+    let answers = answers();
+
+    for day in &runner::DAYS {
+        assert!(
+            answers.iter().any(|answer| answer.day == day.number),
+            "No answer recorded for day {}",
+            day.number,
+        );
+    }
+}
@@ -0,0 +1,53 @@
+//! Days solved one line at a time must give the same answers as when the whole input is parsed
+
+use std::fs::File;
+use std::io::{BufReader, Cursor};
+
+use aoc::{Day, Solved};
+use runner::answers;
+
+fn values(solved: &Solved) -> Vec<(u32, &str)> {
+    solved
+        .answers
+        .iter()
+        .map(|answer| (answer.part.number(), answer.value.as_str()))
+        .collect()
+}
+
+fn streaming_days() -> impl Iterator<Item = &'static Day> {
+    runner::DAYS.iter().filter(|day| day.stream.is_some())
+}
+
+#[test]
+fn puzzle_inputs() {
+    let root = answers::workspace_root();
+
+    for day in streaming_days() {
+        let stream = day.stream.unwrap();
+        let path = root.join(aoc::input::file_name(day.number));
+        let input = std::fs::read_to_string(&path).unwrap();
+        let mut reader = BufReader::new(File::open(&path).unwrap());
+
+        let solved = (day.solve)(&input, None).unwrap();
+        let streamed = stream(&mut reader, None).unwrap();
+
+        assert_eq!(values(&streamed), values(&solved), "Day {}", day.number);
+    }
+}
+
+#[test]
+fn generated_inputs() {
+    assert_eq!(streaming_days().map(|day| day.number).collect::<Vec<_>>(), [1, 2, 4, 7, 9, 12]);
+
+    for day in streaming_days() {
+        let stream = day.stream.unwrap();
+
+        for seed in 0..10 {
+            let input = (day.generate)(seed, 50);
+            let solved = (day.solve)(&input, None).unwrap();
+            let streamed = stream(&mut Cursor::new(&input), None).unwrap();
+
+            assert_eq!(values(&streamed), values(&solved), "Day {} with seed {}", day.number, seed);
+        }
+    }
+}